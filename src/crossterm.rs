@@ -1,15 +1,58 @@
-use std::io::Stdout;
+use std::io::{Stdout, Write};
 
-use crate::out::{Action, Handler};
+use crate::{
+    grid::Frame,
+    out::{Action, Handler, SafeHandler, StringBuffer},
+    style::{blink_enabled, Color, Style},
+};
 
-use crossterm::{cursor::MoveTo, execute, queue, style::Print};
+use crossterm::{
+    cursor::MoveTo,
+    execute, queue,
+    style::{self, Attribute, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    terminal::{self, Clear, ClearType},
+    tty::IsTty,
+};
 /// A basic wrapper for crossterm. Turns this output into crossterm-based output.
+/// Styles are translated into crossterm's style commands.
 pub struct CrosstermHandler;
 impl CrosstermHandler {
     /// Flushes any stray text into the terminal.
     pub fn finish(out: &mut Stdout) -> Result<(), crossterm::ErrorKind> {
         execute!(out)
     }
+    #[doc(hidden)]
+    /// Switches the terminal over to a style. Every attribute is reset first, so nothing is left over from the last style.
+    fn set_style(out: &mut Stdout, s: &Style) -> Result<(), crossterm::ErrorKind> {
+        queue!(
+            out,
+            SetAttribute(Attribute::Reset),
+            SetForegroundColor(convert_color(s.foreground)),
+            SetBackgroundColor(convert_color(s.background))
+        )?;
+        let attributes = [
+            (s.attributes.bold, Attribute::Bold),
+            (s.attributes.dim, Attribute::Dim),
+            (s.attributes.italic, Attribute::Italic),
+            (s.attributes.underline, Attribute::Underlined),
+            (s.attributes.strikethrough, Attribute::CrossedOut),
+            (s.attributes.reverse, Attribute::Reverse),
+            (s.attributes.blink && blink_enabled(), Attribute::SlowBlink),
+        ];
+        for (_, attribute) in attributes.iter().filter(|(set, _)| *set) {
+            queue!(out, SetAttribute(*attribute))?;
+        }
+        Ok(())
+    }
+}
+#[doc(hidden)]
+/// Converts a color into the equivalent crossterm color.
+fn convert_color(color: Color) -> style::Color {
+    match color {
+        Color::Default => style::Color::Reset,
+        Color::Indexed(n) => style::Color::AnsiValue(n),
+        Color::Rgb(r, g, b) => style::Color::Rgb { r, g, b },
+    }
 }
 
 impl Handler for CrosstermHandler {
@@ -23,6 +66,150 @@ impl Handler for CrosstermHandler {
             Action::MoveTo(x, y) => {
                 queue!(out, MoveTo(*x as u16, *y as u16))
             }
+            Action::SetStyle(s) => CrosstermHandler::set_style(out, s),
+        }
+    }
+}
+/**
+A handler that picks its output mode based on where stdout goes.
+If stdout is a terminal, it behaves exactly like a CrosstermHandler.
+If it isn't (for example, when the output is redirected to a file), or if the terminal is a dumb terminal (TERM=dumb)
+that can't move the cursor, the frame is collected into a StringBuffer instead,
+and written out as plain lines of text, without any escape codes, when the handler is flushed.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::crossterm::AutoHandler;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), std::io::Error>{
+let frame = grid::Frame::new(0, 0, 10, 1);
+let mut handler = AutoHandler::new(&frame);
+let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+let mut out = std::io::stdout();
+process.print(&mut handler, &mut out)?;
+handler.flush(&mut out)?;
+# Ok(())
+# }
+```
+*/
+pub enum AutoHandler {
+    Terminal(CrosstermHandler),
+    Plain(StringBuffer),
+}
+impl AutoHandler {
+    /// Creates a new handler for the frame inputted, checking whether stdout is a terminal that can move the cursor.
+    pub fn new(frame: &Frame) -> AutoHandler {
+        let dumb = std::env::var("TERM").is_ok_and(|term| term == "dumb");
+        if std::io::stdout().is_tty() && !dumb {
+            AutoHandler::Terminal(CrosstermHandler)
+        } else {
+            AutoHandler::plain(frame)
+        }
+    }
+    /// Creates a new handler for the frame inputted that always writes plain text, no matter where stdout goes.
+    pub fn plain(frame: &Frame) -> AutoHandler {
+        AutoHandler::Plain(StringBuffer::from_frame(frame))
+    }
+    /// Returns true if the handler is writing plain text instead of drawing onto a terminal.
+    pub fn is_plain(&self) -> bool {
+        matches!(self, AutoHandler::Plain(_))
+    }
+    /// Flushes the handler. On a terminal, this flushes any stray text.
+    /// Otherwise, this writes out everything printed since the last flush as plain text, and starts over with a blank buffer.
+    pub fn flush(&mut self, out: &mut Stdout) -> Result<(), crossterm::ErrorKind> {
+        match self {
+            AutoHandler::Terminal(_) => CrosstermHandler::finish(out),
+            AutoHandler::Plain(buffer) => {
+                for line in buffer.trimmed_lines() {
+                    writeln!(out, "{}", line)?;
+                }
+                out.flush()?;
+                buffer.clear();
+                Ok(())
+            }
+        }
+    }
+}
+impl Handler for AutoHandler {
+    type OutputDevice = Stdout;
+    type Error = crossterm::ErrorKind;
+    fn handle(&mut self, out: &mut Self::OutputDevice, input: &Action) -> Result<(), Self::Error> {
+        match self {
+            AutoHandler::Terminal(handler) => handler.handle(out, input),
+            AutoHandler::Plain(buffer) => {
+                buffer.safe_handle(&mut (), input);
+                Ok(())
+            }
         }
     }
 }
+/**
+A region at the bottom of a normal, scrolling terminal, for status lines and progress bars in programs that don't take over
+the whole screen. Regular output is printed above the region with println(), and scrolls up as usual, while the region stays put.
+The region's frame covers the bottom lines of the terminal, and can be split up and printed to like any other frame.
+# Example
+``` rust no_run
+# use grid_ui::grid;
+# use grid_ui::crossterm::{CrosstermHandler, InlineRegion};
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), crossterm::ErrorKind>{
+let mut out = std::io::stdout();
+let mut region = InlineRegion::new(&mut out, 1)?;
+for i in 0..10 {
+    region.println(&mut out, &format!("Compiled crate {}", i))?;
+    let mut process = region.frame().next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section(format!("Building [{}/10]", i + 1), &mut Truncate, grid::Alignment::Plus);
+    process.print(&mut CrosstermHandler, &mut out)?;
+    CrosstermHandler::finish(&mut out)?;
+}
+region.finish(&mut out)?;
+# Ok(())
+# }
+```
+*/
+pub struct InlineRegion {
+    frame: Frame,
+    lines: usize,
+}
+impl InlineRegion {
+    /// Reserves the bottom lines of the terminal for a region, scrolling anything already there up and out of the way.
+    pub fn new(out: &mut Stdout, lines: usize) -> Result<InlineRegion, crossterm::ErrorKind> {
+        let (width, height) = terminal::size()?;
+        let mut region = InlineRegion {
+            frame: Frame::new(0, 0, 0, 0),
+            lines: 0,
+        };
+        region.resize(width, height, lines);
+        queue!(out, MoveTo(0, height.saturating_sub(1)), Print("\n".repeat(region.lines)))?;
+        execute!(out, Clear(ClearType::FromCursorDown))?;
+        Ok(region)
+    }
+    /// Gets the frame covering the region.
+    pub fn frame(&self) -> &Frame {
+        &self.frame
+    }
+    /// Changes the size of the region, or of the terminal (for example, after a resize event).
+    /// Only the frame is changed - nothing is drawn or cleared, so the region should be printed to again afterwards.
+    pub fn resize(&mut self, width: u16, height: u16, lines: usize) {
+        let height = height as usize;
+        self.lines = lines.min(height);
+        self.frame = Frame::new(0, height - self.lines, width as usize, height);
+    }
+    /// Prints text above the region, as its own line. Everything printed before it scrolls up.
+    /// Text with newlines in it is printed as several lines, and empty text is printed as an empty line.
+    /// The region is cleared, so it should be printed to again afterwards.
+    /// Lines that are wider than the terminal may wrap into the region, and should be avoided.
+    pub fn println(&mut self, out: &mut Stdout, text: &str) -> Result<(), crossterm::ErrorKind> {
+        let top = self.frame.next_frame().start_y as u16;
+        let bottom = self.frame.next_frame().end_y.saturating_sub(1) as u16;
+        for line in text.split('\n') {
+            queue!(out, MoveTo(0, top), Clear(ClearType::FromCursorDown), Print(line), MoveTo(0, bottom), Print("\n"))?;
+        }
+        execute!(out)
+    }
+    /// Clears the region and moves the cursor to its start, so that regular output can continue where the region was.
+    pub fn finish(self, out: &mut Stdout) -> Result<(), crossterm::ErrorKind> {
+        execute!(out, MoveTo(0, self.frame.next_frame().start_y as u16), Clear(ClearType::FromCursorDown))
+    }
+}
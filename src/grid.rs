@@ -1,4 +1,11 @@
-use crate::process::DrawProcess;
+use std::{
+    collections::hash_map::DefaultHasher,
+    convert::TryFrom,
+    hash::{Hash, Hasher},
+    num::TryFromIntError,
+};
+
+use crate::process::{BorderStyle, DrawProcess};
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// This is a frame. It stores the terminal's size in a convenient place.
@@ -6,6 +13,9 @@ use crate::process::DrawProcess;
 /// For examples, see the frame's methods.
 pub struct Frame {
     grid: Grid,
+    claimed: Vec<Grid>,
+    breakpoints: Vec<Breakpoint>,
+    active: Option<usize>,
 }
 impl Frame {
     /**
@@ -26,9 +36,36 @@ impl Frame {
                 end_x: x_max,
                 end_y: y_max,
             },
+            claimed: Vec::new(),
+            breakpoints: Vec::new(),
+            active: None,
         }
     }
     /**
+    Creates a new frame from its top left corner and its size, rather than from its corners.
+    # Example
+    ``` rust
+    # use grid_ui::grid::Frame;
+    # fn main() {
+    let frame: Frame = Frame::with_origin(5, 2, 10, 4);
+    assert_eq!(frame, Frame::new(5, 2, 15, 6));
+    assert_eq!(frame.width(), 10);
+    assert_eq!(frame.height(), 4);
+    # }
+    ```
+    */
+    pub fn with_origin(x: usize, y: usize, width: usize, height: usize) -> Frame {
+        Frame::new(x, y, x + width, y + height)
+    }
+    /// Gets the width of the frame, in columns.
+    pub fn width(&self) -> usize {
+        self.grid.end_x - self.grid.start_x
+    }
+    /// Gets the height of the frame, in lines.
+    pub fn height(&self) -> usize {
+        self.grid.end_y - self.grid.start_y
+    }
+    /**
     Produces a fresh grid, which contains the entire frame.
     # Example
     ``` rust
@@ -46,6 +83,7 @@ impl Frame {
     }
     /**
     Resizes the grid, changing its size.
+    Breakpoints are checked again after resizing. Returns true if a different breakpoint applies now.
     # Example
     ``` rust
     # use grid_ui::grid::Frame;
@@ -60,15 +98,314 @@ impl Frame {
     # }
     ```
     */
-    pub fn resize(&mut self, x_min: usize, y_min: usize, x_max: usize, y_max: usize) {
+    pub fn resize(&mut self, x_min: usize, y_min: usize, x_max: usize, y_max: usize) -> bool {
         self.grid = Grid {
             start_x: x_min,
             start_y: y_min,
             end_x: x_max,
             end_y: y_max,
+        };
+        let old = self.active;
+        self.active = self.breakpoints.iter().position(|x| x.applies(&self.grid));
+        old != self.active
+    }
+    /**
+    Resizes the frame to the size inputted, as (columns, lines), such as the size sent in a terminal resize event.
+    The top left corner of the frame stays where it is, and breakpoints are checked again.
+    Claimed grids that don't fit in the frame anymore are cut down to the part that's still inside it, or stop being claimed
+    if none of them is. Returns every claimed grid that changed, as it was before the resize, so the processes in them can be rebuilt.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() -> Result<(), ()>{
+    let mut frame = Frame::new(0, 0, 20, 10);
+    let mut grid = frame.next_frame();
+    let left = grid.split(&SplitStrategy::new().max_x(5, Alignment::Minus)).ok_or(())?;
+    let right = grid.split(&SplitStrategy::new().max_x(5, Alignment::Plus)).ok_or(())?;
+    assert!(frame.claim(&left) && frame.claim(&right));
+    assert_eq!(frame.handle_resize(18, 10), vec![right]);
+    assert_eq!(frame.next_frame(), Grid {start_x: 0, start_y: 0, end_x: 18, end_y: 10});
+    assert_eq!(frame.claimed(), &[left, Grid {start_x: 15, start_y: 0, end_x: 18, end_y: 10}]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn handle_resize(&mut self, width: u16, height: u16) -> Vec<Grid> {
+        let (x, y) = (self.grid.start_x, self.grid.start_y);
+        self.resize(x, y, x + width as usize, y + height as usize);
+        let mut changed = Vec::new();
+        let frame = &self.grid;
+        self.claimed.retain_mut(|grid| {
+            if frame.contains(grid) {
+                return true;
+            }
+            changed.push(grid.clone());
+            grid.end_x = grid.end_x.min(frame.end_x);
+            grid.end_y = grid.end_y.min(frame.end_y);
+            grid.start_x < grid.end_x && grid.start_y < grid.end_y
+        });
+        changed
+    }
+    /**
+    Creates a frame covering the entire terminal, using the terminal's current size. Only available with the crossterm feature.
+    # Errors
+    Returns an error if the terminal's size can't be found, such as when stdout isn't a terminal.
+    */
+    #[cfg(feature = "crossterm")]
+    pub fn from_terminal() -> Result<Frame, crossterm::ErrorKind> {
+        Ok(Frame::from(crossterm::terminal::size()?))
+    }
+    /**
+    Adds a breakpoint to the frame, returning its index. Breakpoints work like media queries in CSS:
+    whenever the frame is resized, it finds the first breakpoint that applies to its new size.
+    This lets one layout function adapt to narrow or short terminals, by checking breakpoint() before splitting grids up.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() -> Result<(), ()>{
+    let mut frame = Frame::new(0, 0, 100, 30);
+    let narrow = frame.add_breakpoint(Breakpoint::new().max_width(79));
+    assert_eq!(frame.breakpoint(), None);
+    assert!(frame.resize(0, 0, 60, 30));
+    assert_eq!(frame.breakpoint(), Some(narrow));
+    let mut grid = frame.next_frame();
+    // Stacks the sidebar on top of the content if the terminal is narrow.
+    let sidebar = if frame.breakpoint() == Some(narrow) {
+        grid.split(&SplitStrategy::new().max_y(5, Alignment::Minus))
+    } else {
+        grid.split(&SplitStrategy::new().max_x(20, Alignment::Minus))
+    };
+    assert_eq!(sidebar, Some(Grid {start_x: 0, start_y: 0, end_x: 60, end_y: 5}));
+    assert!(!frame.resize(0, 0, 70, 30));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> usize {
+        self.breakpoints.push(breakpoint);
+        self.active = self.breakpoints.iter().position(|x| x.applies(&self.grid));
+        self.breakpoints.len() - 1
+    }
+    /// Gets the index of the first breakpoint that applies to the frame's current size, if there is one.
+    pub fn breakpoint(&self) -> Option<usize> {
+        self.active
+    }
+    /// Removes every breakpoint from the frame.
+    pub fn clear_breakpoints(&mut self) {
+        self.breakpoints.clear();
+        self.active = None;
+    }
+    /**
+    Gets a hash of everything a layout usually depends on: the frame's size, the grids claimed in it, and the breakpoint that applies.
+    If the hash is the same as it was last frame, grids don't need to be split up again.
+    The hash doesn't depend on anything random, so it's the same every time the program is run, but it may change between versions of Rust.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() {
+    let mut frame = Frame::new(0, 0, 80, 24);
+    let hash = frame.layout_hash();
+    frame.resize(0, 0, 80, 24);
+    assert_eq!(frame.layout_hash(), hash);
+    frame.resize(0, 0, 100, 24);
+    assert_ne!(frame.layout_hash(), hash);
+    # }
+    ```
+    */
+    pub fn layout_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.grid.hash(&mut hasher);
+        self.claimed.hash(&mut hasher);
+        self.active.hash(&mut hasher);
+        hasher.finish()
+    }
+    /**
+    Marks a grid as being in use. The frame keeps track of every claimed grid, so it can find space that isn't in use
+    (for example, to place a popup).
+    Returns false, without claiming anything, if the grid isn't inside the frame or overlaps a grid that's already claimed.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() -> Result<(), ()>{
+    let mut frame = Frame::new(0, 0, 10, 10);
+    let mut grid = frame.next_frame();
+    let top = grid.split(&SplitStrategy::new().max_y(3, Alignment::Minus)).ok_or(())?;
+    assert!(frame.claim(&top));
+    assert!(!frame.claim(&top));
+    assert!(frame.is_free(&grid));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn claim(&mut self, grid: &Grid) -> bool {
+        if !self.grid.contains(grid) || !self.is_free(grid) {
+            return false;
+        }
+        self.claimed.push(grid.clone());
+        true
+    }
+    /// Stops tracking a claimed grid, so its space is free again. Returns false if the grid wasn't claimed.
+    pub fn release(&mut self, grid: &Grid) -> bool {
+        let len = self.claimed.len();
+        self.claimed.retain(|x| x != grid);
+        self.claimed.len() != len
+    }
+    /// Stops tracking every claimed grid.
+    pub fn release_all(&mut self) {
+        self.claimed.clear();
+    }
+    /// Gets every claimed grid, in the order they were claimed.
+    pub fn claimed(&self) -> &[Grid] {
+        &self.claimed
+    }
+    /// Returns true if the grid doesn't overlap any claimed grid.
+    pub fn is_free(&self, grid: &Grid) -> bool {
+        self.claimed.iter().all(|x| !x.overlaps(grid))
+    }
+    /**
+    Finds the largest rectangle (by area) inside the frame that doesn't overlap any claimed grid.
+    Returns None if there's no free space at all.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() -> Result<(), ()>{
+    let mut frame = Frame::new(0, 0, 10, 10);
+    let mut grid = frame.next_frame();
+    let sidebar = grid.split(&SplitStrategy::new().max_x(3, Alignment::Minus)).ok_or(())?;
+    let header = grid.split(&SplitStrategy::new().max_y(2, Alignment::Minus)).ok_or(())?;
+    frame.claim(&sidebar);
+    frame.claim(&header);
+    assert_eq!(frame.largest_free(), Some(Grid {start_x: 3, start_y: 2, end_x: 10, end_y: 10}));
+    frame.claim(&grid);
+    assert_eq!(frame.largest_free(), None);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn largest_free(&self) -> Option<Grid> {
+        // Every edge of a free rectangle that doesn't touch the frame's edge touches a claimed grid's edge,
+        // so only those coordinates need to be checked.
+        let clamp = |v: usize, min: usize, max: usize| v.max(min).min(max);
+        let mut xs = vec![self.grid.start_x, self.grid.end_x];
+        let mut ys = vec![self.grid.start_y, self.grid.end_y];
+        for grid in &self.claimed {
+            xs.push(clamp(grid.start_x, self.grid.start_x, self.grid.end_x));
+            xs.push(clamp(grid.end_x, self.grid.start_x, self.grid.end_x));
+            ys.push(clamp(grid.start_y, self.grid.start_y, self.grid.end_y));
+            ys.push(clamp(grid.end_y, self.grid.start_y, self.grid.end_y));
+        }
+        xs.sort_unstable();
+        xs.dedup();
+        ys.sort_unstable();
+        ys.dedup();
+        // The frame is broken up into cells along the coordinates found. Each cell is either entirely free or not.
+        let free = ys
+            .windows(2)
+            .map(|y| {
+                xs.windows(2)
+                    .map(|x| self.is_free(&Grid::new(x[0], y[0], x[1], y[1])))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut best: Option<(usize, Grid)> = None;
+        for top in 0..free.len() {
+            // Tracks which columns are free in every row from the top row to the bottom row.
+            let mut columns = vec![true; xs.len() - 1];
+            for bottom in top..free.len() {
+                for (column, cell) in columns.iter_mut().zip(&free[bottom]) {
+                    *column &= *cell;
+                }
+                let mut start = None;
+                for i in 0..=columns.len() {
+                    match (columns.get(i).copied().unwrap_or(false), start) {
+                        (true, None) => start = Some(i),
+                        (false, Some(first)) => {
+                            let grid = Grid::new(xs[first], ys[top], xs[i], ys[bottom + 1]);
+                            let area = (grid.end_x - grid.start_x) * (grid.end_y - grid.start_y);
+                            if area > best.as_ref().map_or(0, |x| x.0) {
+                                best = Some((area, grid));
+                            }
+                            start = None;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        best.map(|x| x.1)
+    }
+    /**
+    Finds a place for a tooltip (or any other popup) of the size inputted, next to an anchor grid.
+    The tooltip is placed below, above, to the right of, or to the left of the anchor, in that order of preference,
+    lined up with the anchor's top left corner but shifted to stay inside the frame.
+    Places that don't overlap any claimed grid are preferred.
+    Returns None if the tooltip can't fit next to the anchor at all.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() -> Result<(), ()>{
+    let frame = Frame::new(0, 0, 20, 10);
+    let anchor = Grid {start_x: 18, start_y: 2, end_x: 19, end_y: 3};
+    assert_eq!(frame.place_tooltip(&anchor, (5, 2)), Some(Grid {start_x: 15, start_y: 3, end_x: 20, end_y: 5}));
+    let anchor = Grid {start_x: 2, start_y: 9, end_x: 3, end_y: 10};
+    assert_eq!(frame.place_tooltip(&anchor, (5, 2)), Some(Grid {start_x: 2, start_y: 7, end_x: 7, end_y: 9}));
+    assert_eq!(frame.place_tooltip(&anchor, (30, 2)), None);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn place_tooltip(&self, anchor: &Grid, size: (usize, usize)) -> Option<Grid> {
+        let (width, height) = size;
+        let frame = &self.grid;
+        // Shifts a position back so that the tooltip doesn't go past the end of the frame.
+        let fit = |pos: usize, len: usize, min: usize, max: usize| pos.min(max.saturating_sub(len)).max(min);
+        let x = fit(anchor.start_x, width, frame.start_x, frame.end_x);
+        let y = fit(anchor.start_y, height, frame.start_y, frame.end_y);
+        let mut candidates = vec![Grid::new(x, anchor.end_y, x + width, anchor.end_y + height)];
+        if let Some(start_y) = anchor.start_y.checked_sub(height) {
+            candidates.push(Grid::new(x, start_y, x + width, anchor.start_y));
+        }
+        candidates.push(Grid::new(anchor.end_x, y, anchor.end_x + width, y + height));
+        if let Some(start_x) = anchor.start_x.checked_sub(width) {
+            candidates.push(Grid::new(start_x, y, anchor.start_x, y + height));
+        }
+        candidates.retain(|x| frame.contains(x));
+        match candidates.iter().position(|x| self.is_free(x)) {
+            Some(i) => Some(candidates.swap_remove(i)),
+            None => candidates.into_iter().next(),
         }
     }
 }
+/**
+Creates a frame from a terminal size, such as the one returned by crossterm::terminal::size() or sent in a resize event.
+The size is (columns, lines), and the frame starts at the top left corner.
+# Example
+``` rust
+# use grid_ui::grid::*;
+# use std::convert::TryFrom;
+# fn main() -> Result<(), std::num::TryFromIntError>{
+let frame = Frame::from((80, 24));
+assert_eq!(frame, Frame::new(0, 0, 80, 24));
+assert_eq!(<(u16, u16)>::try_from(&frame)?, (80, 24));
+assert_eq!(Grid::from((80, 24)), frame.next_frame());
+assert!(<(u16, u16)>::try_from(&Frame::new(0, 0, 100000, 1)).is_err());
+# Ok(())
+# }
+```
+*/
+impl From<(u16, u16)> for Frame {
+    fn from((width, height): (u16, u16)) -> Self {
+        Frame::with_origin(0, 0, width as usize, height as usize)
+    }
+}
+/// Gets the size of a frame as (columns, lines), the way crossterm represents terminal sizes.
+/// Returns an error if either dimension doesn't fit in a u16.
+impl TryFrom<&Frame> for (u16, u16) {
+    type Error = TryFromIntError;
+    fn try_from(frame: &Frame) -> Result<Self, Self::Error> {
+        Ok((u16::try_from(frame.width())?, u16::try_from(frame.height())?))
+    }
+}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Whether the alignment is in the negative direction [up/left] or in the positive direction [down/right].
@@ -77,17 +414,24 @@ pub enum Alignment {
     Minus,
     Plus,
 }
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Where something is placed along a line: on the left, in the center, or on the right.
+pub enum HorizontalAlignment {
+    Left,
+    Center,
+    Right,
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Maximum {
+    #[default]
     None,
     X(usize, Alignment),
     Y(usize, Alignment),
-}
-impl Default for Maximum {
-    fn default() -> Self {
-        Maximum::None
-    }
+    Auto(usize, usize, Alignment),
+    RatioX(usize, usize, Alignment),
+    RatioY(usize, usize, Alignment),
 }
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
@@ -217,6 +561,112 @@ impl SplitStrategy {
         }
     }
     /**
+    Sets a maximum value along whichever axis the grid is longer in, so that the resulting grid data is stacked
+    next to the rest of the grid if there's room for it, and on top of (or below) it otherwise.
+    Terminal cells are about twice as tall as they are wide, so a grid counts as wide if it has at least twice as many columns as lines.
+    If it's wide, this works like max_x(x, a); otherwise, it works like max_y(y, a).
+    # Panics
+    Only one maximum can be set. Otherwise, this function will panic.
+    # Examples
+    Placing a legend next to a chart, or below it if there isn't room:
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() -> Result<(), ()>{
+    let strategy = SplitStrategy::new().max_auto(10, 3, Alignment::Plus);
+    let mut wide = Frame::new(0, 0, 40, 10).next_frame();
+    let legend = wide.split(&strategy);
+    assert_eq!(legend, Some(Grid {start_x: 30, start_y: 0, end_x: 40, end_y: 10}));
+    let mut narrow = Frame::new(0, 0, 15, 10).next_frame();
+    let legend = narrow.split(&strategy);
+    assert_eq!(legend, Some(Grid {start_x: 0, start_y: 7, end_x: 15, end_y: 10}));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn max_auto(mut self, x: usize, y: usize, a: Alignment) -> Self {
+        if matches!(self.max_size, Maximum::None) {
+            self.max_size = Maximum::Auto(x, y, a);
+            self
+        } else {
+            panic!("A maximum already exists!")
+        }
+    }
+    /**
+    Sets a maximum X value that's a fraction (num / den) of the width of the grid being split, rounded down.
+    Since the fraction is worked out every time the strategy is applied, it stays correct after the frame is resized.
+    The resulting grid will be either on the left or the right, depending on the alignment (left = minus).
+    # Panics
+    Only one maximum can be set. Otherwise, this function will panic.
+    This function also panics if den is 0.
+    # Examples
+    Giving the left pane 30% of the width:
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() -> Result<(), ()>{
+    let strategy = SplitStrategy::new().ratio_x(3, 10, Alignment::Minus);
+    let mut grid = Frame::new(0, 0, 40, 10).next_frame();
+    assert_eq!(grid.split(&strategy), Some(Grid {start_x: 0, start_y: 0, end_x: 12, end_y: 10}));
+    let mut grid = Frame::new(0, 0, 100, 10).next_frame();
+    assert_eq!(grid.split(&strategy), Some(Grid {start_x: 0, start_y: 0, end_x: 30, end_y: 10}));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn ratio_x(mut self, num: usize, den: usize, a: Alignment) -> Self {
+        assert!(den != 0, "The denominator can't be 0!");
+        if matches!(self.max_size, Maximum::None) {
+            self.max_size = Maximum::RatioX(num, den, a);
+            self
+        } else {
+            panic!("A maximum already exists!")
+        }
+    }
+    /**
+    Sets a maximum Y value that's a fraction (num / den) of the height of the grid being split, rounded down.
+    The resulting grid will be either on the top or the bottom, depending on the alignment (top = minus).
+    For more information, see ratio_x().
+    # Panics
+    Only one maximum can be set. Otherwise, this function will panic.
+    This function also panics if den is 0.
+    */
+    pub fn ratio_y(mut self, num: usize, den: usize, a: Alignment) -> Self {
+        assert!(den != 0, "The denominator can't be 0!");
+        if matches!(self.max_size, Maximum::None) {
+            self.max_size = Maximum::RatioY(num, den, a);
+            self
+        } else {
+            panic!("A maximum already exists!")
+        }
+    }
+    /**
+    Sets a maximum X value that's a percentage of the width of the grid being split. This is the same as ratio_x(pct, 100, a).
+    # Panics
+    Only one maximum can be set. Otherwise, this function will panic.
+    */
+    pub fn percent_x(self, pct: usize, a: Alignment) -> Self {
+        self.ratio_x(pct, 100, a)
+    }
+    /**
+    Sets a maximum Y value that's a percentage of the height of the grid being split. This is the same as ratio_y(pct, 100, a).
+    # Panics
+    Only one maximum can be set. Otherwise, this function will panic.
+    # Examples
+    Giving a status bar at the bottom a quarter of the height:
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() -> Result<(), ()>{
+    let mut grid = Frame::new(0, 0, 10, 20).next_frame();
+    let chunk = grid.split(&SplitStrategy::new().percent_y(25, Alignment::Plus));
+    assert_eq!(chunk, Some(Grid {start_x: 0, start_y: 15, end_x: 10, end_y: 20}));
+    assert_eq!(grid, Grid {start_x: 0, start_y: 0, end_x: 10, end_y: 15});
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn percent_y(self, pct: usize, a: Alignment) -> Self {
+        self.ratio_y(pct, 100, a)
+    }
+    /**
     Sets a minimum X value. If the grid cannot give the grid data this amount of length,
     no strategy will be returned.
     # Examples
@@ -317,10 +767,88 @@ impl SplitStrategy {
                     return_value
                 }
             }
+            Maximum::Auto(x, y, alignment) => {
+                // Picks an axis based on the shape of the grid, then applies the strategy as if it had a maximum along that axis.
+                let max_size = if grid.end_x - grid.start_x >= (grid.end_y - grid.start_y) * 2 {
+                    Maximum::X(*x, *alignment)
+                } else {
+                    Maximum::Y(*y, *alignment)
+                };
+                SplitStrategy { max_size, ..self.clone() }.apply(grid)
+            }
+            Maximum::RatioX(num, den, alignment) => {
+                // Works out the size from the current width, then applies the strategy as if it had that size as its maximum.
+                let max_size = Maximum::X((grid.end_x - grid.start_x) * num / den, *alignment);
+                SplitStrategy { max_size, ..self.clone() }.apply(grid)
+            }
+            Maximum::RatioY(num, den, alignment) => {
+                let max_size = Maximum::Y((grid.end_y - grid.start_y) * num / den, *alignment);
+                SplitStrategy { max_size, ..self.clone() }.apply(grid)
+            }
         }
     }
 }
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
+/**
+A condition on the size of a frame, used to pick between layouts. A breakpoint applies if every limit set on it is met.
+Empty breakpoints always apply. For more information, see Frame::add_breakpoint().
+# Example
+``` rust
+# use grid_ui::grid::*;
+# fn main() {
+let mut frame = Frame::new(0, 0, 50, 10);
+let cramped = frame.add_breakpoint(Breakpoint::new().max_width(40).max_height(20));
+let short = frame.add_breakpoint(Breakpoint::new().max_height(20));
+assert_eq!(frame.breakpoint(), Some(short));
+frame.resize(0, 0, 40, 10);
+assert_eq!(frame.breakpoint(), Some(cramped));
+# }
+```
+*/
+pub struct Breakpoint {
+    min_width: Option<usize>,
+    max_width: Option<usize>,
+    min_height: Option<usize>,
+    max_height: Option<usize>,
+}
+impl Breakpoint {
+    /// Creates an empty breakpoint, which always applies.
+    pub fn new() -> Breakpoint {
+        Breakpoint::default()
+    }
+    /// Makes the breakpoint only apply if the frame is at least v columns wide.
+    pub fn min_width(mut self, v: usize) -> Self {
+        self.min_width = Some(v);
+        self
+    }
+    /// Makes the breakpoint only apply if the frame is at most v columns wide.
+    pub fn max_width(mut self, v: usize) -> Self {
+        self.max_width = Some(v);
+        self
+    }
+    /// Makes the breakpoint only apply if the frame is at least v lines tall.
+    pub fn min_height(mut self, v: usize) -> Self {
+        self.min_height = Some(v);
+        self
+    }
+    /// Makes the breakpoint only apply if the frame is at most v lines tall.
+    pub fn max_height(mut self, v: usize) -> Self {
+        self.max_height = Some(v);
+        self
+    }
+    #[doc(hidden)]
+    /// Checks whether the breakpoint applies to a grid.
+    fn applies(&self, grid: &Grid) -> bool {
+        let width = grid.end_x - grid.start_x;
+        let height = grid.end_y - grid.start_y;
+        self.min_width.map_or(true, |v| width >= v)
+            && self.max_width.map_or(true, |v| width <= v)
+            && self.min_height.map_or(true, |v| height >= v)
+            && self.max_height.map_or(true, |v| height <= v)
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// A grid - basically, a square meant to resemble a portion of a terminal. Can be split up into other grids.
 /// Cloning a grid is bad practice! Use it only if you must.
@@ -370,6 +898,74 @@ impl Grid {
         strategy.apply(self)
     }
     /**
+    Carves a grid of at most v columns out of the middle of this one, which is useful for centered columns and dialogs.
+    Returns the space to the left of it, the centered grid, and the space to the right of it, in that order.
+    If the space left over can't be split evenly, the extra column goes on the right. Grids with nothing in them are still returned.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() {
+    let grid = Frame::new(0, 0, 80, 24).next_frame();
+    let (_, column, _) = grid.center_x(40);
+    let (above, dialog, below) = column.center_y(9);
+    assert_eq!(dialog, Grid {start_x: 20, start_y: 7, end_x: 60, end_y: 16});
+    assert_eq!(above, Grid {start_x: 20, start_y: 0, end_x: 60, end_y: 7});
+    assert_eq!(below, Grid {start_x: 20, start_y: 16, end_x: 60, end_y: 24});
+    # }
+    ```
+    */
+    pub fn center_x(&self, v: usize) -> (Grid, Grid, Grid) {
+        let size = v.min(self.end_x - self.start_x);
+        let start = self.start_x + (self.end_x - self.start_x - size) / 2;
+        (
+            Grid::new(self.start_x, self.start_y, start, self.end_y),
+            Grid::new(start, self.start_y, start + size, self.end_y),
+            Grid::new(start + size, self.start_y, self.end_x, self.end_y),
+        )
+    }
+    /// Carves a grid of at most v lines out of the middle of this one.
+    /// Returns the space above it, the centered grid, and the space below it, in that order.
+    /// For more information, see center_x().
+    pub fn center_y(&self, v: usize) -> (Grid, Grid, Grid) {
+        let size = v.min(self.end_y - self.start_y);
+        let start = self.start_y + (self.end_y - self.start_y - size) / 2;
+        (
+            Grid::new(self.start_x, self.start_y, self.end_x, start),
+            Grid::new(self.start_x, start, self.end_x, start + size),
+            Grid::new(self.start_x, start + size, self.end_x, self.end_y),
+        )
+    }
+    /**
+    Returns true if the two grids share any space.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() {
+    let grid = Grid {start_x: 0, start_y: 0, end_x: 5, end_y: 5};
+    assert!(grid.overlaps(&Grid {start_x: 4, start_y: 4, end_x: 10, end_y: 10}));
+    assert!(!grid.overlaps(&Grid {start_x: 5, start_y: 0, end_x: 10, end_y: 5}));
+    # }
+    ```
+    */
+    pub fn overlaps(&self, other: &Grid) -> bool {
+        self.start_x < other.end_x && other.start_x < self.end_x && self.start_y < other.end_y && other.start_y < self.end_y
+    }
+    /**
+    Returns true if the other grid is entirely inside of this one.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() {
+    let grid = Grid {start_x: 0, start_y: 0, end_x: 5, end_y: 5};
+    assert!(grid.contains(&Grid {start_x: 1, start_y: 1, end_x: 5, end_y: 5}));
+    assert!(!grid.contains(&Grid {start_x: 4, start_y: 4, end_x: 10, end_y: 10}));
+    # }
+    ```
+    */
+    pub fn contains(&self, other: &Grid) -> bool {
+        self.start_x <= other.start_x && other.end_x <= self.end_x && self.start_y <= other.start_y && other.end_y <= self.end_y
+    }
+    /**
     Extends the grid in the either direction, either positive or negative, if the input is compatible
     (ie grids are next to each other and of similar dimensions)
     If the two grids are incompatible, it returns an error and gives the grid back. 
@@ -390,7 +986,6 @@ impl Grid {
     # }
     ```
     */
-    
     pub fn extend(&mut self, grid: Grid) -> Result<(), Grid> {
         if self.start_x == grid.start_x && self.end_x == grid.end_x {
             if self.end_y == grid.start_y {
@@ -432,6 +1027,48 @@ impl Grid {
     pub fn into_process(self, strategy: DividerStrategy) -> DrawProcess {
         DrawProcess::new(self, strategy)
     }
+    /**
+    Converts the grid into a DrawProcess with a border drawn around it. Text is only written inside the border,
+    so the process is one character smaller on every side. A title can be embedded in the top of the border with DrawProcess::set_title().
+    # Errors
+    Returns the grid if it's smaller than 3 by 3, since there wouldn't be any space inside the border.
+    # Examples
+    ``` rust
+    # use grid_ui::out;
+    # use grid_ui::process::BorderStyle;
+    # use grid_ui::trim::Truncate;
+    # use grid_ui::grid::*;
+    # fn main() -> Result<(), ()>{
+    let grid = Frame::new(0, 0, 8, 4).next_frame();
+    let mut process = grid.into_bordered_process(DividerStrategy::Beginning, BorderStyle::Ascii).map_err(|_| ())?;
+    assert_eq!(process.width(), 6);
+    process.add_to_section("Some text".to_string(), &mut Truncate, Alignment::Plus);
+    assert_eq!(process.to_string(), "+------+\n|Some t|\n|      |\n+------+\n".to_string());
+    let too_small = Grid {start_x: 0, start_y: 0, end_x: 2, end_y: 4};
+    assert_eq!(too_small.clone().into_bordered_process(DividerStrategy::Beginning, BorderStyle::Plain), Err(too_small));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn into_bordered_process(self, strategy: DividerStrategy, border: BorderStyle) -> Result<DrawProcess, Grid> {
+        if self.end_x < self.start_x + 3 || self.end_y < self.start_y + 3 {
+            return Err(self);
+        }
+        Ok(DrawProcess::bordered(self, strategy, border))
+    }
+}
+/// Creates a grid that covers an entire terminal of the size inputted, as (columns, lines). See the From implementation for Frame.
+impl From<(u16, u16)> for Grid {
+    fn from((width, height): (u16, u16)) -> Self {
+        Grid::new(0, 0, width as usize, height as usize)
+    }
+}
+/// Gets the size of a grid as (columns, lines). Returns an error if either dimension doesn't fit in a u16.
+impl TryFrom<&Grid> for (u16, u16) {
+    type Error = TryFromIntError;
+    fn try_from(grid: &Grid) -> Result<Self, Self::Error> {
+        Ok((u16::try_from(grid.end_x - grid.start_x)?, u16::try_from(grid.end_y - grid.start_y)?))
+    }
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -444,4 +1081,19 @@ pub enum DividerStrategy {
     End,
     Halfway,
     Pos(usize),
+    /// Keeps the content vertically centered. Both sides share all of the space available,
+    /// and the divider is moved after each line is added so that there's as much space above the content as below it.
+    /// ``` rust
+    /// # use grid_ui::grid::*;
+    /// # use grid_ui::trim::Truncate;
+    /// # fn main() {
+    /// let mut process = Frame::new(0, 0, 5, 5).next_frame().into_process(DividerStrategy::Centered);
+    /// process.add_to_section("Two".to_string(), &mut Truncate, Alignment::Plus);
+    /// process.add_to_section("One".to_string(), &mut Truncate, Alignment::Minus);
+    /// process.add_to_section("Three".to_string(), &mut Truncate, Alignment::Plus);
+    /// assert_eq!(process.to_string(), "     \nOne  \nTwo  \nThree\n     \n".to_string());
+    /// assert_eq!(process.space_left(Alignment::Minus), 2);
+    /// # }
+    /// ```
+    Centered,
 }
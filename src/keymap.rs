@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use crossterm::event::{Event, KeyEvent};
+
+/// The result of sending a key to a keymap.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum KeyResult<C> {
+    /// The keys pressed so far make up a command.
+    Command(C),
+    /// The keys pressed so far are the start of at least one command. More keys are needed.
+    Pending,
+    /// The keys pressed don't make up any command. They're discarded.
+    Unbound,
+    /// Some of the keys pressed so far made up a command, but the keymap was waiting for a longer command, which the keys
+    /// after them don't continue. Contains that command, followed by the result of sending the keys after it again.
+    Interrupted(C, Box<KeyResult<C>>),
+}
+/**
+A keymap maps keys, or sequences of keys (chords such as `g g`), to commands.
+Commands can be anything - generally, they're an enum defined by the user.
+Keys are sent to the keymap one at a time. While a sequence is incomplete, the keymap remembers the keys pressed so far.
+If a sequence is both a command and the start of a longer command, the keymap waits for more keys.
+Call timeout() to settle for the shorter command (for example, if no key has been pressed for a while).
+If a key that doesn't continue the longer command is pressed instead, the longest command among the keys pressed so far is returned,
+and the keys after it are sent to the keymap again.
+# Example
+``` rust
+# use grid_ui::keymap::*;
+# use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+#[derive(Debug, Clone, PartialEq)]
+enum Command {
+    Top,
+    Down,
+    Quit,
+}
+# fn main() {
+let mut keymap = Keymap::new()
+    .bind(&[KeyCode::Char('g').into(), KeyCode::Char('g').into()], Command::Top)
+    .bind(&[KeyCode::Char('j').into()], Command::Down)
+    .bind(&[KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)], Command::Quit);
+assert_eq!(keymap.handle(KeyCode::Char('j').into()), KeyResult::Command(Command::Down));
+assert_eq!(keymap.handle(KeyCode::Char('g').into()), KeyResult::Pending);
+assert_eq!(keymap.handle(KeyCode::Char('g').into()), KeyResult::Command(Command::Top));
+assert_eq!(keymap.handle(KeyCode::Char('c').into()), KeyResult::Unbound);
+assert_eq!(keymap.handle(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)), KeyResult::Command(Command::Quit));
+# }
+```
+Abandoning a sequence part of the way through
+``` rust
+# use grid_ui::keymap::*;
+# use crossterm::event::KeyCode;
+# fn main() {
+let mut keymap = Keymap::new()
+    .bind(&[KeyCode::Char('g').into(), KeyCode::Char('g').into()], "top")
+    .bind(&[KeyCode::Char('j').into()], "down");
+assert_eq!(keymap.handle(KeyCode::Char('g').into()), KeyResult::Pending);
+assert_eq!(keymap.handle(KeyCode::Char('j').into()), KeyResult::Command("down"));
+assert!(keymap.pending().is_empty());
+# }
+```
+Interrupting a command that's waiting for a longer one
+``` rust
+# use grid_ui::keymap::*;
+# use crossterm::event::KeyCode;
+# fn main() {
+let mut keymap = Keymap::new()
+    .bind(&[KeyCode::Char('g').into()], "go")
+    .bind(&[KeyCode::Char('g').into(), KeyCode::Char('g').into()], "top")
+    .bind(&[KeyCode::Char('j').into()], "down");
+assert_eq!(keymap.handle(KeyCode::Char('g').into()), KeyResult::Pending);
+assert_eq!(keymap.handle(KeyCode::Char('j').into()), KeyResult::Interrupted("go", Box::new(KeyResult::Command("down"))));
+assert_eq!(keymap.handle(KeyCode::Char('g').into()), KeyResult::Pending);
+assert_eq!(keymap.handle(KeyCode::Char('x').into()), KeyResult::Interrupted("go", Box::new(KeyResult::Unbound)));
+assert_eq!(keymap.handle(KeyCode::Char('g').into()), KeyResult::Pending);
+assert_eq!(keymap.handle(KeyCode::Char('g').into()), KeyResult::Command("top"));
+# }
+```
+The command can be several keys back, and timing out works the same way
+``` rust
+# use grid_ui::keymap::*;
+# use crossterm::event::KeyCode;
+# fn main() {
+let mut keymap = Keymap::new()
+    .bind(&[KeyCode::Char('a').into()], "A")
+    .bind(&[KeyCode::Char('a').into(), KeyCode::Char('b').into(), KeyCode::Char('c').into()], "ABC")
+    .bind(&[KeyCode::Char('b').into()], "B");
+assert_eq!(keymap.handle(KeyCode::Char('a').into()), KeyResult::Pending);
+assert_eq!(keymap.handle(KeyCode::Char('b').into()), KeyResult::Pending);
+let result = keymap.handle(KeyCode::Char('x').into());
+assert_eq!(result, KeyResult::Interrupted("A", Box::new(KeyResult::Interrupted("B", Box::new(KeyResult::Unbound)))));
+assert_eq!(keymap.handle(KeyCode::Char('a').into()), KeyResult::Pending);
+assert_eq!(keymap.handle(KeyCode::Char('b').into()), KeyResult::Pending);
+assert_eq!(keymap.timeout(), KeyResult::Interrupted("A", Box::new(KeyResult::Command("B"))));
+assert_eq!(keymap.timeout(), KeyResult::Unbound);
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap<C> {
+    bindings: HashMap<Vec<KeyEvent>, C>,
+    pending: Vec<KeyEvent>,
+}
+impl<C: Clone> Keymap<C> {
+    /// Creates an empty keymap.
+    pub fn new() -> Keymap<C> {
+        Keymap {
+            bindings: HashMap::new(),
+            pending: Vec::new(),
+        }
+    }
+    /// Binds a sequence of keys to a command, replacing any command already bound to the sequence.
+    /// # Panics
+    /// Panics if the sequence of keys is empty.
+    pub fn bind(mut self, keys: &[KeyEvent], command: C) -> Self {
+        assert!(!keys.is_empty(), "A command needs at least one key!");
+        self.bindings.insert(keys.to_vec(), command);
+        self
+    }
+    /// Removes the command bound to a sequence of keys, returning it if there was one.
+    pub fn unbind(&mut self, keys: &[KeyEvent]) -> Option<C> {
+        self.bindings.remove(keys)
+    }
+    /// Gets the keys pressed so far in an incomplete sequence.
+    pub fn pending(&self) -> &[KeyEvent] {
+        &self.pending
+    }
+    /// Sends a key to the keymap.
+    pub fn handle(&mut self, key: KeyEvent) -> KeyResult<C> {
+        self.pending.push(key);
+        match self.resolve() {
+            KeyResult::Unbound => {
+                let keys = std::mem::take(&mut self.pending);
+                match self.longest_command(&keys) {
+                    // The longest command is returned, and the keys after it might start a sequence on their own.
+                    Some((len, command)) => KeyResult::Interrupted(command, Box::new(self.replay(&keys[len..]))),
+                    // Without a command, the sequence is discarded, but the new key might start a sequence on its own.
+                    None if keys.len() > 1 => self.handle(key),
+                    None => KeyResult::Unbound,
+                }
+            }
+            result => result,
+        }
+    }
+    /// Sends an event to the keymap. Anything that isn't a key press is ignored, and returns None.
+    pub fn handle_event(&mut self, event: &Event) -> Option<KeyResult<C>> {
+        match event {
+            Event::Key(key) => Some(self.handle(*key)),
+            _ => None,
+        }
+    }
+    /// Gives up on waiting for more keys. If the keys pressed so far make up a command, it is returned.
+    /// Otherwise, the longest command they start with is returned, and the keys after it are sent to the keymap again
+    /// (and timed out as well, if they're waiting for more keys). Never returns KeyResult::Pending.
+    pub fn timeout(&mut self) -> KeyResult<C> {
+        let keys = std::mem::take(&mut self.pending);
+        match self.bindings.get(&keys) {
+            Some(command) => KeyResult::Command(command.clone()),
+            None => match self.longest_command(&keys) {
+                Some((len, command)) => {
+                    let rest = self.replay(&keys[len..]);
+                    let rest = if self.pending.is_empty() { rest } else { chain(rest, self.timeout()) };
+                    KeyResult::Interrupted(command, Box::new(rest))
+                }
+                None => KeyResult::Unbound,
+            },
+        }
+    }
+    #[doc(hidden)]
+    /// Finds the longest command that the keys inputted start with (not counting all of the keys), and its length.
+    fn longest_command(&self, keys: &[KeyEvent]) -> Option<(usize, C)> {
+        (1..keys.len()).rev().find_map(|len| self.bindings.get(&keys[..len]).map(|command| (len, command.clone())))
+    }
+    #[doc(hidden)]
+    /// Sends keys to the keymap one at a time, combining their results.
+    fn replay(&mut self, keys: &[KeyEvent]) -> KeyResult<C> {
+        keys.iter().fold(KeyResult::Pending, |res, key| {
+            let next = self.handle(*key);
+            chain(res, next)
+        })
+    }
+    #[doc(hidden)]
+    /// Checks the pending keys against the bindings. The keys are kept if they don't make up any command.
+    fn resolve(&mut self) -> KeyResult<C> {
+        let longer = self.bindings.keys().any(|x| x.len() > self.pending.len() && x.starts_with(&self.pending));
+        if longer {
+            return KeyResult::Pending;
+        }
+        match self.bindings.get(&self.pending) {
+            Some(command) => {
+                self.pending.clear();
+                KeyResult::Command(command.clone())
+            }
+            None => KeyResult::Unbound,
+        }
+    }
+}
+#[doc(hidden)]
+/// Combines the results of two keys sent one after the other, so that no command is lost.
+fn chain<C>(first: KeyResult<C>, then: KeyResult<C>) -> KeyResult<C> {
+    match first {
+        KeyResult::Pending | KeyResult::Unbound => then,
+        KeyResult::Command(command) => KeyResult::Interrupted(command, Box::new(then)),
+        KeyResult::Interrupted(command, rest) => KeyResult::Interrupted(command, Box::new(chain(*rest, then))),
+    }
+}
+impl<C: Clone> Default for Keymap<C> {
+    fn default() -> Self {
+        Keymap::new()
+    }
+}
@@ -1,6 +1,9 @@
 pub mod grid;
 pub mod out;
 pub mod process;
+pub mod style;
 pub mod trim;
 #[cfg(feature = "crossterm")]
 pub mod crossterm;
+#[cfg(feature = "crossterm")]
+pub mod keymap;
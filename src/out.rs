@@ -1,14 +1,23 @@
+use std::{collections::HashMap, io::Write, marker::PhantomData};
+
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::grid::Frame;
+use crate::{
+    grid::{Frame, Grid},
+    style::{blink_enabled, Color, Style},
+    trim,
+};
 
-/// Currently, an action is either printing a string or moving to a location.
-/// The first value is the x location, the second is the y location.
+/// Currently, an action is either printing a string, moving to a location, or changing the style text is printed in.
+/// For MoveTo, the first value is the x location, the second is the y location.
+/// A style applies to everything printed after it, until the next SetStyle. Processes always go back to the default style
+/// at the end of a styled line. Handlers that don't use styles are never sent SetStyle (see Handler::uses_styles()).
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Action<'a> {
     Print(&'a str),
     MoveTo(usize, usize),
+    SetStyle(Style),
 }
 /**
 A handler is a structure that can convert actions into an output on an output device.
@@ -34,6 +43,11 @@ pub trait Handler {
     type OutputDevice;
     type Error;
     fn handle(&mut self, out: &mut Self::OutputDevice, input: &Action) -> Result<(), Self::Error>;
+    /// Whether the handler uses styles. Processes print each line to handlers that don't as a single Print, without any SetStyle.
+    /// This is true by default.
+    fn uses_styles(&self) -> bool {
+        true
+    }
 }
 /**
 A handler that is "safe", ie doesn't return an error. All safe handlers are also handlers - you can use them as such. 
@@ -57,12 +71,17 @@ assert_eq!(output_device, "          \n          \nSome stuff\n          \n".to_
 pub trait SafeHandler {
     type OutputDevice;
     fn safe_handle(&mut self, out: &mut Self::OutputDevice, input: &Action);
+    /// Whether the handler uses styles. For more information, see Handler::uses_styles().
+    fn uses_styles(&self) -> bool {
+        true
+    }
 }
 /**
 A handler that outputs the text to a string, as lines. It does not pay attention to the location used.
 This means that it won't panic at all, and will generally accept whatever text is thrown at it.
 This makes it useful for debug purposes.
 However, it doesn't do any formatting, and doesn't change behavior based on locations - only where you call it matters.
+For other ways of writing text out to a string, see OutToStringWith.
 # Example
 ``` rust
 # use grid_ui::grid;
@@ -109,8 +128,188 @@ impl SafeHandler for OutToString {
                 out.push('\n')
             }
             Action::MoveTo(_, _) => {}
+            Action::SetStyle(_) => {}
+        }
+    }
+    fn uses_styles(&self) -> bool {
+        false
+    }
+}
+/// The ways an OutToStringWith handler can write text out to a string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StringMode {
+    /// Each piece of text printed is written out as a line, without regards for location, exactly like OutToString.
+    Raw,
+    /// Each piece of text printed is written out followed by the separator, instead of a newline, without regards for location.
+    Separated(String),
+    /// Text is drawn onto a StringBuffer, with regards for location, and written out as lines when the handler is flushed.
+    Positional(StringBuffer),
+}
+/**
+A handler that writes text out to a string, in a mode picked when it's created (see StringMode).
+Unlike OutToString, this can place text where it's printed, so layouts with several chunks next to each other come out right.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Ignore;
+# fn main() -> Result<(), ()>{
+let frame = grid::Frame::new(0, 0, 10, 1);
+let mut left = frame.next_frame();
+let right = left.split(&grid::SplitStrategy::new().max_x(5, grid::Alignment::Plus)).ok_or(())?;
+let mut left_process = left.into_process(grid::DividerStrategy::Beginning);
+let mut right_process = right.into_process(grid::DividerStrategy::Beginning);
+right_process.add_to_section("stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
+left_process.add_to_section("Some".to_string(), &mut Ignore, grid::Alignment::Plus);
+for (mode, expected) in vec![
+    (StringMode::Raw, "stuff\nSome\n"),
+    (StringMode::Separated(" | ".to_string()), "stuff | Some | "),
+    (StringMode::Positional(StringBuffer::from_frame(&frame)), "Some stuff\n"),
+] {
+    let mut handler = OutToStringWith::new(mode);
+    let mut output = String::new();
+    right_process.print(&mut handler, &mut output)?;
+    left_process.print(&mut handler, &mut output)?;
+    handler.flush(&mut output);
+    assert_eq!(output, expected.to_string());
+}
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OutToStringWith {
+    mode: StringMode,
+}
+impl OutToStringWith {
+    /// Creates a handler that writes text out in the mode inputted.
+    pub fn new(mode: StringMode) -> OutToStringWith {
+        OutToStringWith { mode }
+    }
+    /// Gets the mode the handler writes text out in.
+    pub fn mode(&self) -> &StringMode {
+        &self.mode
+    }
+    /// In positional mode, writes out everything drawn since the last flush as lines, and starts over with a blank buffer.
+    /// Other modes write text out as soon as it's printed, so this does nothing.
+    pub fn flush(&mut self, out: &mut String) {
+        if let StringMode::Positional(buffer) = &mut self.mode {
+            for line in buffer.rows() {
+                for cell in line {
+                    out.push_str(cell.as_str());
+                }
+                out.push('\n');
+            }
+            buffer.clear();
+        }
+    }
+}
+impl SafeHandler for OutToStringWith {
+    type OutputDevice = String;
+    fn safe_handle(&mut self, out: &mut String, input: &Action) {
+        match &mut self.mode {
+            StringMode::Raw => OutToString.safe_handle(out, input),
+            StringMode::Separated(separator) => match input {
+                Action::Print(s) => {
+                    out.push_str(s);
+                    out.push_str(separator);
+                }
+                Action::MoveTo(_, _) => {}
+                Action::SetStyle(_) => {}
+            },
+            StringMode::Positional(buffer) => buffer.safe_handle(&mut (), input),
         }
     }
+    fn uses_styles(&self) -> bool {
+        false
+    }
+}
+/// The bytes written at the end of each line by OutToBytes.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Newline {
+    /// A line feed (\n), used on most systems.
+    Lf,
+    /// A carriage return followed by a line feed (\r\n), used on Windows and by many network protocols.
+    CrLf,
+}
+/**
+A handler that writes text out to a vector of bytes, as lines, for when the exact bytes matter (for example, when
+sending a frame over a network protocol or saving it to a file). Like OutToString, it does not pay attention to the location used.
+The newline written after each line can be chosen. If the handler is ASCII-only, text containing anything other than
+ASCII is rejected, and returned as an error without anything being written.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), String>{
+let mut process = grid::Frame::new(0, 0, 5, 2).next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Some".to_string(), &mut Truncate, grid::Alignment::Plus);
+let mut output: Vec<u8> = Vec::new();
+process.print(&mut OutToBytes::new().newline(Newline::CrLf).ascii_only(true), &mut output)?;
+assert_eq!(b"Some \r\n     \r\n".to_vec(), output);
+process.add_to_section("Café".to_string(), &mut Truncate, grid::Alignment::Plus);
+let mut output: Vec<u8> = Vec::new();
+let e = process.print(&mut OutToBytes::new().ascii_only(true), &mut output).unwrap_err();
+assert_eq!(e, "Café ".to_string());
+assert_eq!(b"Some \n".to_vec(), output);
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutToBytes {
+    newline: Newline,
+    ascii_only: bool,
+}
+impl OutToBytes {
+    /// Creates a handler that ends lines with \n, and accepts any text.
+    pub fn new() -> OutToBytes {
+        OutToBytes {
+            newline: Newline::Lf,
+            ascii_only: false,
+        }
+    }
+    /// Sets the newline written after each line.
+    pub fn newline(mut self, newline: Newline) -> Self {
+        self.newline = newline;
+        self
+    }
+    /// Sets whether text containing anything other than ASCII is rejected.
+    pub fn ascii_only(mut self, ascii_only: bool) -> Self {
+        self.ascii_only = ascii_only;
+        self
+    }
+}
+impl Default for OutToBytes {
+    fn default() -> Self {
+        OutToBytes::new()
+    }
+}
+impl Handler for OutToBytes {
+    type OutputDevice = Vec<u8>;
+    type Error = String;
+    fn handle(&mut self, out: &mut Vec<u8>, input: &Action) -> Result<(), String> {
+        match input {
+            Action::Print(s) => {
+                if self.ascii_only && !s.is_ascii() {
+                    return Err(s.to_string());
+                }
+                out.extend_from_slice(s.as_bytes());
+                match self.newline {
+                    Newline::Lf => out.push(b'\n'),
+                    Newline::CrLf => out.extend_from_slice(b"\r\n"),
+                }
+            }
+            Action::MoveTo(_, _) => {}
+            Action::SetStyle(_) => {}
+        }
+        Ok(())
+    }
+    fn uses_styles(&self) -> bool {
+        false
+    }
 }
 impl<H: SafeHandler> Handler for H {
     type OutputDevice = H::OutputDevice;
@@ -119,6 +318,9 @@ impl<H: SafeHandler> Handler for H {
         self.safe_handle(out, input);
         Ok(())
     }
+    fn uses_styles(&self) -> bool {
+        SafeHandler::uses_styles(self)
+    }
 }
 /**
 A more complicated version of the structure OutToString. This modifies a string buffer
@@ -182,21 +384,112 @@ process.print(&mut small_output, &mut ())?; // panics
 ```
 
 */
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "StringBufferData", into = "StringBufferData"))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct StringBuffer {
-    pub contents: Vec<Vec<String>>,
+    cells: Vec<Cell>,
+    width: usize,
+    height: usize,
     pub offset_x: usize,
     pub offset_y: usize,
     current_x: usize,
     current_y: usize,
 }
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+/// How a StringBuffer is serialized: the grapheme in each cell, line by line.
+/// When deserializing, lines that are shorter than the first line are filled in with blank cells, and longer lines are cut off.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct StringBufferData {
+    contents: Vec<Vec<String>>,
+    offset_x: usize,
+    offset_y: usize,
+    current_x: usize,
+    current_y: usize,
+}
+#[cfg(feature = "serde")]
+impl From<StringBuffer> for StringBufferData {
+    fn from(buffer: StringBuffer) -> Self {
+        StringBufferData {
+            contents: buffer.rows().map(|x| x.iter().map(|cell| cell.as_str().to_string()).collect()).collect(),
+            offset_x: buffer.offset_x,
+            offset_y: buffer.offset_y,
+            current_x: buffer.current_x,
+            current_y: buffer.current_y,
+        }
+    }
+}
+#[cfg(feature = "serde")]
+impl From<StringBufferData> for StringBuffer {
+    fn from(data: StringBufferData) -> Self {
+        let width = data.contents.first().map_or(0, Vec::len);
+        let mut res = StringBuffer::new(data.offset_x, data.offset_y, data.offset_x + width, data.offset_y + data.contents.len());
+        for (y, line) in data.contents.iter().enumerate() {
+            for (x, grapheme) in line.iter().take(width).enumerate() {
+                res.cells[y * width + x] = Cell::new(grapheme);
+            }
+        }
+        res.current_x = data.current_x;
+        res.current_y = data.current_y;
+        res
+    }
+}
+#[doc(hidden)]
+/// The number of bytes a grapheme can take up and still be stored inside a cell. This covers almost every grapheme,
+/// including most emoji sequences, while keeping a cell as small as a String.
+const INLINE_CELL: usize = 21;
+#[doc(hidden)]
+/// A single cell of a StringBuffer, holding the grapheme drawn there and its width.
+/// Graphemes are stored inside the cell itself, so that a buffer doesn't need a separate allocation for every cell.
+/// Only graphemes that are too long to fit are stored on the heap. The cells after a wide grapheme are empty.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Cell {
+    Inline { len: u8, width: u8, bytes: [u8; INLINE_CELL] },
+    Long { width: u8, grapheme: Box<str> },
+}
+impl Cell {
+    /// Creates a cell holding the grapheme inputted.
+    fn new(grapheme: &str) -> Cell {
+        let width = trim::width(grapheme).min(u8::MAX as usize) as u8;
+        if grapheme.len() > INLINE_CELL {
+            return Cell::Long { width, grapheme: grapheme.into() };
+        }
+        let mut bytes = [0; INLINE_CELL];
+        bytes[..grapheme.len()].copy_from_slice(grapheme.as_bytes());
+        Cell::Inline { len: grapheme.len() as u8, width, bytes }
+    }
+    /// Creates a blank cell.
+    fn blank() -> Cell {
+        Cell::new(" ")
+    }
+    /// Gets the grapheme in the cell.
+    fn as_str(&self) -> &str {
+        match self {
+            Cell::Inline { len, bytes, .. } => std::str::from_utf8(&bytes[..*len as usize]).expect("Safe unwrap"),
+            Cell::Long { grapheme, .. } => grapheme,
+        }
+    }
+    /// Gets the number of columns the grapheme in the cell takes up.
+    fn width(&self) -> usize {
+        match self {
+            Cell::Inline { width, .. } | Cell::Long { width, .. } => *width as usize,
+        }
+    }
+}
+impl std::fmt::Debug for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
 
 impl StringBuffer {
     /// Creates a new StringBuffer from 4 dimensions. 
     pub fn new(min_x: usize, min_y: usize, max_x: usize, max_y: usize) -> StringBuffer {
+        let (width, height) = (max_x - min_x, max_y - min_y);
         StringBuffer {
-            contents: vec![vec![" ".to_string(); max_x - min_x]; max_y - min_y],
+            cells: vec![Cell::blank(); width * height],
+            width,
+            height,
             current_x: 0,
             current_y: 0,
             offset_x: min_x,
@@ -208,18 +501,198 @@ impl StringBuffer {
         let g = f.next_frame();
         StringBuffer::new(g.start_x, g.start_y, g.end_x, g.end_y)
     }
+    /**
+    Resizes the StringBuffer to the same dimensions as the frame inputted, such as after the terminal is resized.
+    Content that's inside both the old and the new dimensions is kept where it is, and everything else starts out blank.
+    Wide graphemes that would be cut in half by the new edges are blanked out.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::*;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let frame = grid::Frame::new(0, 0, 10, 2);
+    let mut output = StringBuffer::from_frame(&frame);
+    let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("More".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.print(&mut output, &mut ())?;
+    output.resize(&grid::Frame::new(0, 1, 6, 3));
+    assert_eq!(output.clone().lines(), vec!["More  ".to_string(), "      ".to_string()]);
+    output.resize(&grid::Frame::new(0, 0, 12, 1));
+    assert_eq!(output.clone().lines(), vec!["            ".to_string()]);
+    let frame = grid::Frame::new(0, 0, 4, 1);
+    let mut output = StringBuffer::from_frame(&frame);
+    let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("漢字".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.print(&mut output, &mut ())?;
+    let mut cut = output.clone();
+    cut.resize(&grid::Frame::new(0, 0, 3, 1));
+    assert_eq!(cut.lines(), vec!["漢 ".to_string()]);
+    output.resize(&grid::Frame::new(1, 0, 4, 1));
+    assert_eq!(output.lines(), vec![" 字".to_string()]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn resize(&mut self, f: &Frame) {
+        let g = f.next_frame();
+        let mut resized = StringBuffer::new(g.start_x, g.start_y, g.end_x, g.end_y);
+        for (i, cell) in self.cells.drain(..).enumerate() {
+            let (x, y) = (self.offset_x + i % self.width, self.offset_y + i / self.width);
+            // The first cell of a line can't be the rest of a wide grapheme, and the last cells can't start one that doesn't fit.
+            let cut = (x == g.start_x && cell.as_str().is_empty()) || x + cell.width() > g.end_x;
+            if g.start_x <= x && x < g.end_x && g.start_y <= y && y < g.end_y && !cut {
+                resized.cells[(y - g.start_y) * resized.width + x - g.start_x] = cell;
+            }
+        }
+        resized.current_x = (self.offset_x + self.current_x).saturating_sub(g.start_x);
+        resized.current_y = (self.offset_y + self.current_y).saturating_sub(g.start_y);
+        *self = resized;
+    }
+    #[doc(hidden)]
+    /// Gets the cells in each line of the buffer.
+    fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        (0..self.height).map(move |y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+    #[doc(hidden)]
+    /// Gets the index of a cell from its location in the buffer.
+    /// # Panics
+    /// Panics if the location is outside of the buffer.
+    fn index(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside of the string buffer", x, y);
+        y * self.width + x
+    }
+    /**
+    Gets the grapheme drawn at a location. Like actions, this uses the same coordinates as the frame the buffer was created from.
+    Blank cells hold a single space, and the cells after a wide grapheme are empty.
+    Returns None if the location is outside of the buffer.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::*;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let frame = grid::Frame::new(2, 0, 6, 1);
+    let mut output = StringBuffer::from_frame(&frame);
+    let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("漢a".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.print(&mut output, &mut ())?;
+    assert_eq!(output.get(2, 0), Some("漢"));
+    assert_eq!(output.get(3, 0), Some(""));
+    assert_eq!(output.get(4, 0), Some("a"));
+    assert_eq!(output.get(5, 0), Some(" "));
+    assert_eq!(output.get(0, 0), None);
+    output.clear();
+    assert_eq!(output.get(2, 0), Some(" "));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn get(&self, x: usize, y: usize) -> Option<&str> {
+        let (x, y) = (x.checked_sub(self.offset_x)?, y.checked_sub(self.offset_y)?);
+        if x < self.width && y < self.height {
+            Some(self.cells[y * self.width + x].as_str())
+        } else {
+            None
+        }
+    }
+    /**
+    Gets the grapheme drawn in each cell, line by line. Blank cells hold a single space, and the cells after a wide grapheme are empty.
+    The contents can't be written to directly: draw onto the buffer with actions instead, or blank it out with clear().
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::*;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let frame = grid::Frame::new(0, 0, 3, 1);
+    let mut output = StringBuffer::from_frame(&frame);
+    let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("漢a".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.print(&mut output, &mut ())?;
+    assert_eq!(output.contents(), vec![vec!["漢", "", "a"]]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn contents(&self) -> Vec<Vec<&str>> {
+        self.rows().map(|x| x.iter().map(Cell::as_str).collect()).collect()
+    }
+    /// Blanks out every cell.
+    pub fn clear(&mut self) {
+        self.cells.fill(Cell::blank());
+    }
     /// Prints the StringBuffer.
     pub fn finalize(&self) {
-        for line in &self.contents {
-            for block in line {
-                print!("{}", block);
+        for line in self.rows() {
+            for cell in line {
+                print!("{}", cell.as_str());
             }
             println!();
         }
     }
     /// Returns the StringBuffer lines, collected into strings (instead of each grapheme being individually displayed)
     pub fn lines(self) -> Vec<String> {
-        self.contents.into_iter().map(|x| x.into_iter().collect::<String>()).collect::<Vec<_>>()
+        self.rows().map(|x| x.iter().map(Cell::as_str).collect::<String>()).collect::<Vec<_>>()
+    }
+    /**
+    Returns the StringBuffer lines without any blank padding - trailing whitespace is removed from each line,
+    and trailing blank lines are removed entirely. This is useful for tests, which generally shouldn't care about
+    the exact-width padding added by strategies such as Truncate.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::*;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let frame = grid::Frame::new(0, 0, 10, 3);
+    let mut output: StringBuffer = StringBuffer::from_frame(&frame);
+    let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("More".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.print(&mut output, &mut ())?;
+    assert_eq!(vec!["Some stuff".to_string(), "More".to_string()], output.trimmed_lines());
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn trimmed_lines(&self) -> Vec<String> {
+        let mut res = self
+            .rows()
+            .map(|x| x.iter().map(Cell::as_str).collect::<String>().trim_end().to_string())
+            .collect::<Vec<_>>();
+        while res.last().is_some_and(|x| x.is_empty()) {
+            res.pop();
+        }
+        res
+    }
+    /**
+    Compares the StringBuffer to the expected lines, ignoring trailing whitespace and trailing blank lines on both sides.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::*;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let frame = grid::Frame::new(0, 0, 10, 3);
+    let mut output: StringBuffer = StringBuffer::from_frame(&frame);
+    let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.print(&mut output, &mut ())?;
+    assert!(output.eq_ignoring_padding(&["Some"]));
+    assert!(output.eq_ignoring_padding(&["Some   ", ""]));
+    assert!(!output.eq_ignoring_padding(&["  Some"]));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn eq_ignoring_padding<S: AsRef<str>>(&self, expected: &[S]) -> bool {
+        let mut expected = expected.iter().map(|x| x.as_ref().trim_end()).collect::<Vec<_>>();
+        while expected.last().is_some_and(|x| x.is_empty()) {
+            expected.pop();
+        }
+        self.trimmed_lines() == expected
     }
 }
 impl SafeHandler for StringBuffer {
@@ -228,14 +701,919 @@ impl SafeHandler for StringBuffer {
     fn safe_handle(&mut self, _: &mut (), input: &Action) {
         match input {
             Action::Print(v) => {
-                for (i, line) in v.grapheme_indices(true) {
-                    self.contents[self.current_y][self.current_x + i] = line.to_string();
+                for grapheme in v.graphemes(true) {
+                    match trim::grapheme_width(grapheme) {
+                        // Zero-width graphemes are drawn on top of the grapheme before them.
+                        0 if self.current_x > 0 => {
+                            let i = self.index(self.current_x - 1, self.current_y);
+                            self.cells[i] = Cell::new(&format!("{}{}", self.cells[i].as_str(), grapheme));
+                        }
+                        width => {
+                            // Wide graphemes take up several cells. The cells after the first one are left empty.
+                            let i = self.index(self.current_x, self.current_y);
+                            let end = (i + width).min((self.current_y + 1) * self.width);
+                            self.cells[i] = Cell::new(grapheme);
+                            for cell in &mut self.cells[i + 1..end.max(i + 1)] {
+                                *cell = Cell::new("");
+                            }
+                            self.current_x += width.max(1);
+                        }
+                    }
                 }
             }
             Action::MoveTo(x, y) => {
                 self.current_x = *x - self.offset_x;
                 self.current_y = *y - self.offset_y;
             }
+            Action::SetStyle(_) => {}
+        }
+    }
+}
+/**
+A handler that draws text onto a sparse canvas, where only cells that have been written to take up memory.
+This is useful for very large virtual canvases (such as a scrollback buffer thousands of lines long), where a StringBuffer
+would have to allocate every cell up front. Unlike a StringBuffer, any location can be written to.
+Blank cells (single spaces) aren't stored, so writing blank space over a cell frees it.
+Part of the canvas can be copied into a StringBuffer for display with viewport().
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let mut canvas = SparseBuffer::new();
+let mut process = grid::Frame::new(0, 9998, 10, 10000).next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Far down".to_string(), &mut Truncate, grid::Alignment::Plus);
+process.print(&mut canvas, &mut ())?;
+assert_eq!(canvas.len(), 7);
+assert_eq!(canvas.get(0, 9998), Some("F"));
+let view = canvas.viewport(&grid::Frame::new(4, 9997, 8, 9999).next_frame());
+assert_eq!(view.lines(), vec!["    ".to_string(), "down".to_string()]);
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SparseBuffer {
+    cells: HashMap<(usize, usize), String>,
+    current_x: usize,
+    current_y: usize,
+}
+impl SparseBuffer {
+    /// Creates a blank canvas.
+    pub fn new() -> SparseBuffer {
+        SparseBuffer::default()
+    }
+    /// Gets the grapheme in a cell, or None if the cell is blank.
+    pub fn get(&self, x: usize, y: usize) -> Option<&str> {
+        self.cells.get(&(x, y)).map(|cell| cell.as_str())
+    }
+    /// Gets the number of cells that aren't blank.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+    /// Returns true if every cell is blank.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+    /// Blanks out every cell, freeing their memory.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+    /// Copies the part of the canvas covered by the grid inputted into a StringBuffer of the same dimensions.
+    pub fn viewport(&self, grid: &Grid) -> StringBuffer {
+        let mut res = StringBuffer::new(grid.start_x, grid.start_y, grid.end_x, grid.end_y);
+        if res.cells.len() < self.cells.len() {
+            // The viewport is smaller than the canvas, so each of its cells is looked up.
+            for (i, cell) in res.cells.iter_mut().enumerate() {
+                if let Some(v) = self.cells.get(&(grid.start_x + i % res.width, grid.start_y + i / res.width)) {
+                    *cell = Cell::new(v);
+                }
+            }
+        } else {
+            for ((x, y), v) in &self.cells {
+                if grid.start_x <= *x && *x < grid.end_x && grid.start_y <= *y && *y < grid.end_y {
+                    res.cells[(y - grid.start_y) * res.width + x - grid.start_x] = Cell::new(v);
+                }
+            }
+        }
+        res
+    }
+}
+impl SafeHandler for SparseBuffer {
+    type OutputDevice = ();
+    fn safe_handle(&mut self, _: &mut (), input: &Action) {
+        match input {
+            Action::Print(v) => {
+                for grapheme in v.graphemes(true) {
+                    if grapheme == " " {
+                        self.cells.remove(&(self.current_x, self.current_y));
+                    } else {
+                        self.cells.insert((self.current_x, self.current_y), grapheme.to_string());
+                    }
+                    // Wide graphemes take up several cells. The cells after the first one are stored as empty cells.
+                    let width = trim::grapheme_width(grapheme).max(1);
+                    for x in self.current_x + 1..self.current_x + width {
+                        self.cells.insert((x, self.current_y), String::new());
+                    }
+                    self.current_x += width;
+                }
+            }
+            Action::MoveTo(x, y) => {
+                self.current_x = *x;
+                self.current_y = *y;
+            }
+            Action::SetStyle(_) => {}
+        }
+    }
+}
+/**
+A compositor that shows part of a sparse canvas on the screen. Content is printed onto the canvas once, in virtual coordinates
+(the viewport is a handler, which prints onto its canvas), and scrolling moves the viewport across the canvas, instead of
+trimming and printing everything again. This makes scrolling through long logs cheap.
+The viewport is as big as the frame it's shown in, and starts at the top left of the canvas.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let screen = grid::Frame::new(0, 0, 8, 2);
+let mut viewport = Viewport::new(&screen);
+// The log is printed onto the canvas once.
+let mut log = grid::Frame::new(0, 0, 8, 1000).next_frame().into_process(grid::DividerStrategy::Beginning);
+for i in 0..1000 {
+    log.add_to_section(format!("Line {}", i), &mut Truncate, grid::Alignment::Plus);
+}
+log.print(&mut viewport, &mut ())?;
+viewport.scroll_to(0, 500);
+let mut output = StringBuffer::from_frame(&screen);
+viewport.show(&mut output, &mut ())?;
+assert_eq!(output.trimmed_lines(), vec!["Line 500".to_string(), "Line 501".to_string()]);
+viewport.scroll_by(0, -600);
+assert_eq!(viewport.position(), (0, 0));
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Viewport {
+    pub canvas: SparseBuffer,
+    screen: Grid,
+    x: usize,
+    y: usize,
+}
+impl Viewport {
+    /// Creates a viewport with a blank canvas, shown in the frame inputted.
+    pub fn new(frame: &Frame) -> Viewport {
+        Viewport {
+            canvas: SparseBuffer::new(),
+            screen: frame.next_frame(),
+            x: 0,
+            y: 0,
+        }
+    }
+    /// Changes the frame the viewport is shown in, such as after the terminal is resized. The viewport stays where it is on the canvas.
+    pub fn resize(&mut self, frame: &Frame) {
+        self.screen = frame.next_frame();
+    }
+    /// Gets the position of the top left of the viewport on the canvas.
+    pub fn position(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+    /// Moves the top left of the viewport to a position on the canvas.
+    pub fn scroll_to(&mut self, x: usize, y: usize) {
+        self.x = x;
+        self.y = y;
+    }
+    /// Moves the viewport across the canvas. It can't be moved past the top or the left of the canvas.
+    pub fn scroll_by(&mut self, x: isize, y: isize) {
+        self.x = self.x.saturating_add_signed(x);
+        self.y = self.y.saturating_add_signed(y);
+    }
+    /// Gets the part of the canvas that's visible, in virtual coordinates.
+    pub fn visible(&self) -> Grid {
+        Grid {
+            start_x: self.x,
+            start_y: self.y,
+            end_x: self.x + self.screen.end_x - self.screen.start_x,
+            end_y: self.y + self.screen.end_y - self.screen.start_y,
+        }
+    }
+    /// Prints the visible part of the canvas onto the screen, using a handler.
+    /// # Errors
+    /// Returns an error if the handler returns an error.
+    pub fn show<H: Handler>(&self, handler: &mut H, out: &mut H::OutputDevice) -> Result<(), H::Error> {
+        for (i, line) in self.canvas.viewport(&self.visible()).lines().iter().enumerate() {
+            handler.handle(out, &Action::MoveTo(self.screen.start_x, self.screen.start_y + i))?;
+            handler.handle(out, &Action::Print(line))?;
+        }
+        Ok(())
+    }
+}
+impl SafeHandler for Viewport {
+    type OutputDevice = ();
+    fn safe_handle(&mut self, out: &mut (), input: &Action) {
+        self.canvas.safe_handle(out, input)
+    }
+}
+/**
+A handler that draws text onto an SVG image, which is useful for showing what a layout looks like in web documentation.
+Each piece of text printed is placed on a grid of cells, and stretched to fill exactly as many cells as it's wide (see trim::width()),
+so that it lines up no matter which monospace font is used. Blank text isn't drawn.
+Text is drawn in its foreground color, or in white if it's printed in the default color. Other parts of its style are ignored.
+The image is collected as the handler is used, and can be generated with to_svg().
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let frame = grid::Frame::new(0, 0, 10, 2);
+let mut output = OutToSvg::from_frame(&frame);
+let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("a < b".to_string(), &mut Truncate, grid::Alignment::Plus);
+process.print(&mut output, &mut ())?;
+let svg = output.to_svg();
+assert!(svg.starts_with("<svg "));
+assert!(svg.contains(">a &lt; b     </text>"));
+assert!(svg.contains("width=\"80\" height=\"32\""));
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OutToSvg {
+    grid: Grid,
+    cell_width: usize,
+    cell_height: usize,
+    current_x: usize,
+    current_y: usize,
+    style: Style,
+    elements: String,
+}
+impl OutToSvg {
+    /// Creates a new image covering the frame inputted, with cells 8 pixels wide and 16 pixels tall.
+    pub fn from_frame(f: &Frame) -> OutToSvg {
+        OutToSvg {
+            current_x: 0,
+            current_y: 0,
+            grid: f.next_frame(),
+            cell_width: 8,
+            cell_height: 16,
+            style: Style::new(),
+            elements: String::new(),
+        }
+    }
+    /// Sets the size of each cell, in pixels.
+    pub fn cell_size(mut self, width: usize, height: usize) -> Self {
+        self.cell_width = width;
+        self.cell_height = height;
+        self
+    }
+    /// Generates the image, as the contents of an SVG file.
+    pub fn to_svg(&self) -> String {
+        let width = (self.grid.end_x - self.grid.start_x) * self.cell_width;
+        let height = (self.grid.end_y - self.grid.start_y) * self.cell_height;
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"monospace\" font-size=\"{}\">\n\
+             <rect width=\"100%\" height=\"100%\" fill=\"black\"/>\n{}</svg>\n",
+            width, height, self.cell_height * 3 / 4, self.elements
+        )
+    }
+}
+impl SafeHandler for OutToSvg {
+    type OutputDevice = ();
+    fn safe_handle(&mut self, _: &mut (), input: &Action) {
+        match input {
+            Action::Print(v) => {
+                let length = trim::width(v);
+                if !v.trim().is_empty() {
+                    let mut escaped = String::new();
+                    for c in v.chars() {
+                        match c {
+                            '&' => escaped.push_str("&amp;"),
+                            '<' => escaped.push_str("&lt;"),
+                            '>' => escaped.push_str("&gt;"),
+                            c => escaped.push(c),
+                        }
+                    }
+                    let fill = match self.style.foreground.to_rgb() {
+                        Some((r, g, b)) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+                        None => "white".to_string(),
+                    };
+                    self.elements.push_str(&format!(
+                        "<text x=\"{}\" y=\"{}\" textLength=\"{}\" lengthAdjust=\"spacingAndGlyphs\" fill=\"{}\" xml:space=\"preserve\">{}</text>\n",
+                        self.current_x.saturating_sub(self.grid.start_x) * self.cell_width,
+                        self.current_y.saturating_sub(self.grid.start_y) * self.cell_height + self.cell_height * 3 / 4,
+                        length * self.cell_width,
+                        fill,
+                        escaped
+                    ));
+                }
+                self.current_x += length;
+            }
+            Action::MoveTo(x, y) => {
+                self.current_x = *x;
+                self.current_y = *y;
+            }
+            Action::SetStyle(style) => self.style = *style,
         }
     }
 }
+/**
+A handler that writes raw ANSI escape sequences into anything that implements std::io::Write, such as a file, a pipe, or a vector of bytes.
+Moves are written as cursor position sequences, text is written as it is, and styles are written as SGR sequences.
+Unlike the crossterm module, this doesn't need the crossterm feature. Nothing is flushed - that's up to the writer.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::style::{Style, StyledText};
+# use grid_ui::trim::{Styled, Truncate};
+# fn main() -> Result<(), std::io::Error>{
+let frame = grid::Frame::new(2, 1, 6, 2);
+let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+let text = StyledText::new().plain("a").span("bc", Style::indexed(9).bold());
+process.add_to_section(text, &mut Styled(Truncate), grid::Alignment::Plus);
+let mut output: Vec<u8> = Vec::new();
+process.print(&mut AnsiHandler::new(), &mut output)?;
+assert_eq!(String::from_utf8(output).unwrap(), "\x1b[2;3Ha\x1b[0;38;5;9;49;1mbc\x1b[0;39;49m ".to_string());
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnsiHandler<W: Write> {
+    writer: PhantomData<W>,
+}
+impl<W: Write> AnsiHandler<W> {
+    /// Creates a handler that writes into a writer of type W.
+    pub fn new() -> AnsiHandler<W> {
+        AnsiHandler { writer: PhantomData }
+    }
+    #[doc(hidden)]
+    /// Gets the parameters of the SGR sequence that sets a color, starting at 30 for the foreground or 40 for the background.
+    fn color(color: Color, base: u8) -> String {
+        match color {
+            Color::Default => format!("{}", base + 9),
+            Color::Indexed(n) => format!("{};5;{}", base + 8, n),
+            Color::Rgb(r, g, b) => format!("{};2;{};{};{}", base + 8, r, g, b),
+        }
+    }
+}
+impl<W: Write> Default for AnsiHandler<W> {
+    fn default() -> Self {
+        AnsiHandler::new()
+    }
+}
+impl<W: Write> Handler for AnsiHandler<W> {
+    type OutputDevice = W;
+    type Error = std::io::Error;
+    fn handle(&mut self, out: &mut W, input: &Action) -> Result<(), std::io::Error> {
+        match input {
+            Action::Print(v) => out.write_all(v.as_bytes()),
+            // Cursor positions start at 1, not 0.
+            Action::MoveTo(x, y) => write!(out, "\x1b[{};{}H", y + 1, x + 1),
+            Action::SetStyle(style) => {
+                // Everything is reset first, so nothing is left over from the last style.
+                let mut parameters = vec!["0".to_string(), Self::color(style.foreground, 30), Self::color(style.background, 40)];
+                let attributes = [
+                    (style.attributes.bold, "1"),
+                    (style.attributes.dim, "2"),
+                    (style.attributes.italic, "3"),
+                    (style.attributes.underline, "4"),
+                    (style.attributes.strikethrough, "9"),
+                    (style.attributes.reverse, "7"),
+                    (style.attributes.blink && blink_enabled(), "5"),
+                ];
+                parameters.extend(attributes.iter().filter(|(set, _)| *set).map(|(_, code)| code.to_string()));
+                write!(out, "\x1b[{}m", parameters.join(";"))
+            }
+        }
+    }
+}
+/// What a Retry handler does with an action that still fails after every attempt.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FailurePolicy {
+    /// Returns the error, aborting the print.
+    Abort,
+    /// Drops the rest of the frame. Every action is ignored until next_frame() is called.
+    DropFrame,
+}
+/**
+A handler that wraps another handler, retrying actions that fail.
+This is useful for handlers that can fail temporarily, such as ones writing to a nonblocking output.
+Errors are only retried if the retry condition returns true for them (by default, every error is retried).
+If an action still fails, the failure policy decides whether the error is returned or the frame is dropped.
+# Examples
+Retrying:
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Truncate;
+struct Flaky(usize);
+impl Handler for Flaky {
+    type OutputDevice = String;
+    type Error = ();
+    fn handle(&mut self, out: &mut String, input: &Action) -> Result<(), ()> {
+        self.0 += 1;
+        if self.0 % 2 == 0 {
+            return Err(());
+        }
+        OutToString.handle(out, input)
+    }
+}
+# fn main() -> Result<(), ()>{
+let mut process = grid::Frame::new(0, 0, 10, 1).next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+let mut output = String::new();
+assert!(process.print(&mut Flaky(0), &mut output).is_err());
+let mut output = String::new();
+process.print(&mut Retry::new(Flaky(0)).attempts(2), &mut output)?;
+assert_eq!(output, "Some stuff\n".to_string());
+# Ok(())
+# }
+```
+Dropping frames:
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Truncate;
+struct Broken;
+impl Handler for Broken {
+    type OutputDevice = ();
+    type Error = ();
+    fn handle(&mut self, _: &mut (), _: &Action) -> Result<(), ()> {
+        Err(())
+    }
+}
+# fn main() -> Result<(), ()>{
+let mut process = grid::Frame::new(0, 0, 10, 1).next_frame().into_process(grid::DividerStrategy::Beginning);
+let mut handler = Retry::new(Broken).policy(FailurePolicy::DropFrame);
+process.print(&mut handler, &mut ())?;
+assert!(handler.next_frame());
+assert!(!handler.next_frame());
+# Ok(())
+# }
+```
+*/
+pub struct Retry<H: Handler> {
+    pub handler: H,
+    attempts: usize,
+    policy: FailurePolicy,
+    retry_if: fn(&H::Error) -> bool,
+    dropped: bool,
+}
+impl<H: Handler> Retry<H> {
+    /// Wraps a handler. By default, every action is attempted once, and errors are returned.
+    pub fn new(handler: H) -> Retry<H> {
+        Retry {
+            handler,
+            attempts: 1,
+            policy: FailurePolicy::Abort,
+            retry_if: |_| true,
+            dropped: false,
+        }
+    }
+    /// Sets the number of times an action is attempted before giving up. At least one attempt is always made.
+    pub fn attempts(mut self, attempts: usize) -> Self {
+        self.attempts = attempts.max(1);
+        self
+    }
+    /// Sets what happens when an action can't be handled.
+    pub fn policy(mut self, policy: FailurePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+    /// Sets which errors are retried. Any other error is treated as a failure immediately.
+    pub fn retry_if(mut self, retry_if: fn(&H::Error) -> bool) -> Self {
+        self.retry_if = retry_if;
+        self
+    }
+    /// Starts a new frame. Returns true if the previous frame was dropped, meaning it needs to be redrawn.
+    pub fn next_frame(&mut self) -> bool {
+        std::mem::replace(&mut self.dropped, false)
+    }
+}
+impl<H: Handler> Handler for Retry<H> {
+    type OutputDevice = H::OutputDevice;
+    type Error = H::Error;
+    fn handle(&mut self, out: &mut Self::OutputDevice, input: &Action) -> Result<(), Self::Error> {
+        if self.dropped {
+            return Ok(());
+        }
+        let mut attempts = 1;
+        let error = loop {
+            match self.handler.handle(out, input) {
+                Ok(()) => return Ok(()),
+                Err(e) if attempts < self.attempts && (self.retry_if)(&e) => attempts += 1,
+                Err(e) => break e,
+            }
+        };
+        match self.policy {
+            FailurePolicy::Abort => Err(error),
+            FailurePolicy::DropFrame => {
+                self.dropped = true;
+                Ok(())
+            }
+        }
+    }
+    fn uses_styles(&self) -> bool {
+        self.handler.uses_styles()
+    }
+}
+/**
+A handler that wraps another handler, making sure nothing is printed outside of a grid.
+Text that goes past the end of the grid is cut off, and text that starts outside of the grid isn't printed at all.
+Every time this happens, it's counted as a violation. This protects neighboring grids from strategies
+such as trim::Ignore, which don't force text to fit.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Ignore;
+# fn main() -> Result<(), ()>{
+let frame = grid::Frame::new(0, 0, 10, 1);
+let mut left = frame.next_frame();
+let right = left.split(&grid::SplitStrategy::new().max_x(5, grid::Alignment::Plus)).ok_or(())?;
+let mut left_process = left.into_process(grid::DividerStrategy::Beginning);
+let mut right_process = right.into_process(grid::DividerStrategy::Beginning);
+left_process.add_to_section("Too long!".to_string(), &mut Ignore, grid::Alignment::Plus);
+right_process.add_to_section("Fits".to_string(), &mut Ignore, grid::Alignment::Plus);
+let mut output = Clip::new(StringBuffer::from_frame(&frame), right_process.grid());
+right_process.print(&mut output, &mut ())?;
+output.set_grid(left_process.grid());
+left_process.print(&mut output, &mut ())?; // would panic without clipping
+assert_eq!(output.violations(), 1);
+assert_eq!(vec!["Too lFits ".to_string()], output.handler.lines());
+# Ok(())
+# }
+```
+*/
+pub struct Clip<H: Handler> {
+    pub handler: H,
+    grid: Grid,
+    current_x: usize,
+    current_y: usize,
+    violations: usize,
+}
+impl<H: Handler> Clip<H> {
+    /// Wraps a handler, clipping everything to the grid inputted.
+    pub fn new(handler: H, grid: Grid) -> Clip<H> {
+        Clip {
+            handler,
+            current_x: grid.start_x,
+            current_y: grid.start_y,
+            grid,
+            violations: 0,
+        }
+    }
+    /// Changes the grid text is clipped to. This should be called before printing each process.
+    pub fn set_grid(&mut self, grid: Grid) {
+        self.grid = grid;
+    }
+    /// Gets the number of times text had to be clipped.
+    pub fn violations(&self) -> usize {
+        self.violations
+    }
+    /// Resets the number of violations back to zero.
+    pub fn reset_violations(&mut self) {
+        self.violations = 0;
+    }
+}
+impl<H: Handler> Handler for Clip<H> {
+    type OutputDevice = H::OutputDevice;
+    type Error = H::Error;
+    fn handle(&mut self, out: &mut Self::OutputDevice, input: &Action) -> Result<(), Self::Error> {
+        match input {
+            Action::Print(v) => {
+                let inside = self.grid.start_y <= self.current_y && self.current_y < self.grid.end_y && self.grid.start_x <= self.current_x;
+                let space = if inside { self.grid.end_x.saturating_sub(self.current_x) } else { 0 };
+                let length = trim::width(v);
+                if length <= space {
+                    self.current_x += length;
+                    return self.handler.handle(out, input);
+                }
+                self.violations += 1;
+                if space == 0 {
+                    return Ok(());
+                }
+                let clipped = &v[..trim::fit(v, space)];
+                self.current_x += space;
+                self.handler.handle(out, &Action::Print(clipped))
+            }
+            Action::MoveTo(x, y) => {
+                self.current_x = *x;
+                self.current_y = *y;
+                self.handler.handle(out, input)
+            }
+            Action::SetStyle(_) => self.handler.handle(out, input),
+        }
+    }
+    fn uses_styles(&self) -> bool {
+        self.handler.uses_styles()
+    }
+}
+/// Statistics about the actions passed through a Count handler.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Stats {
+    /// The number of Print actions.
+    pub prints: usize,
+    /// The number of MoveTo actions.
+    pub moves: usize,
+    /// The number of bytes of text printed.
+    pub bytes: usize,
+}
+/**
+A handler that wraps another handler, counting the actions that pass through it and the bytes of text they print.
+Replaying a sequence of frames through this handler (with OutToString or StringBuffer inside it, for example)
+shows how much output they produce, so tests can catch changes that suddenly make printing much more expensive.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let frame = grid::Frame::new(0, 0, 10, 2);
+let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+let mut output = Count::new(StringBuffer::from_frame(&frame));
+process.print_if_changed(&mut output, &mut ())?;
+process.print_if_changed(&mut output, &mut ())?;
+assert_eq!(output.stats(), Stats { prints: 2, moves: 2, bytes: 20 });
+assert!(output.stats().bytes < 100);
+# Ok(())
+# }
+```
+*/
+pub struct Count<H: Handler> {
+    pub handler: H,
+    stats: Stats,
+}
+impl<H: Handler> Count<H> {
+    /// Wraps a handler, starting with every count at zero.
+    pub fn new(handler: H) -> Count<H> {
+        Count {
+            handler,
+            stats: Stats::default(),
+        }
+    }
+    /// Gets the statistics counted so far.
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+    /// Resets every count back to zero, returning the statistics counted before the reset.
+    pub fn reset(&mut self) -> Stats {
+        std::mem::take(&mut self.stats)
+    }
+}
+impl<H: Handler> Handler for Count<H> {
+    type OutputDevice = H::OutputDevice;
+    type Error = H::Error;
+    fn handle(&mut self, out: &mut Self::OutputDevice, input: &Action) -> Result<(), Self::Error> {
+        match input {
+            Action::Print(v) => {
+                self.stats.prints += 1;
+                self.stats.bytes += v.len();
+            }
+            Action::MoveTo(_, _) => self.stats.moves += 1,
+            Action::SetStyle(_) => {}
+        }
+        self.handler.handle(out, input)
+    }
+    fn uses_styles(&self) -> bool {
+        self.handler.uses_styles()
+    }
+}
+/**
+A handler that wraps another handler, only passing style changes on when the style actually changes.
+Processes go back to the default style at the end of every styled line, so a frame full of styled lines would otherwise
+switch styles back and forth on every line. Here, a style change is held back until text is printed, and dropped if
+the text would be printed in the same style anyway. Call finish() at the end of each frame, so the terminal is left in the default style.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::style::{Style, StyledText};
+# use grid_ui::trim::{Split, Styled};
+struct CountStyles(usize);
+impl SafeHandler for CountStyles {
+    type OutputDevice = ();
+    fn safe_handle(&mut self, _: &mut (), input: &Action) {
+        if let Action::SetStyle(_) = input {
+            self.0 += 1;
+        }
+    }
+}
+# fn main() -> Result<(), ()>{
+let frame = grid::Frame::new(0, 0, 6, 3);
+let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+let text = StyledText::new().span("abcdefghij", Style::rgb(255, 0, 0));
+process.add_to_section(text, &mut Styled(Split), grid::Alignment::Plus);
+let mut every_change = CountStyles(0);
+process.print(&mut every_change, &mut ())?;
+assert_eq!(every_change.0, 4);
+let mut output = StyleDiff::new(CountStyles(0));
+process.print(&mut output, &mut ())?;
+output.finish(&mut ())?;
+assert_eq!(output.handler.0, 2);
+# Ok(())
+# }
+```
+*/
+pub struct StyleDiff<H: Handler> {
+    pub handler: H,
+    current: Option<Style>,
+    pending: Style,
+}
+impl<H: Handler> StyleDiff<H> {
+    /// Wraps a handler, assuming that the output device starts out in the default style.
+    pub fn new(handler: H) -> StyleDiff<H> {
+        StyleDiff {
+            handler,
+            current: Some(Style::new()),
+            pending: Style::new(),
+        }
+    }
+    /// Switches back to the default style, if the output device isn't in it already.
+    pub fn finish(&mut self, out: &mut H::OutputDevice) -> Result<(), H::Error> {
+        self.pending = Style::new();
+        self.apply(out)
+    }
+    /// Forgets which style the output device is in, so the next style change is always passed on.
+    /// This is useful if something else might have changed the style, such as another program.
+    pub fn invalidate(&mut self) {
+        self.current = None;
+    }
+    #[doc(hidden)]
+    /// Passes on the style change being held back, if it changes the style.
+    fn apply(&mut self, out: &mut H::OutputDevice) -> Result<(), H::Error> {
+        if self.current != Some(self.pending) {
+            self.handler.handle(out, &Action::SetStyle(self.pending))?;
+            self.current = Some(self.pending);
+        }
+        Ok(())
+    }
+}
+impl<H: Handler> Handler for StyleDiff<H> {
+    type OutputDevice = H::OutputDevice;
+    type Error = H::Error;
+    fn handle(&mut self, out: &mut Self::OutputDevice, input: &Action) -> Result<(), Self::Error> {
+        match input {
+            Action::Print(v) => {
+                if !v.is_empty() {
+                    self.apply(out)?;
+                }
+                self.handler.handle(out, input)
+            }
+            Action::MoveTo(_, _) => self.handler.handle(out, input),
+            Action::SetStyle(style) => {
+                self.pending = *style;
+                Ok(())
+            }
+        }
+    }
+    fn uses_styles(&self) -> bool {
+        self.handler.uses_styles()
+    }
+}
+/**
+A handler that wraps another handler, only passing on the changes between one frame and the next.
+Everything printed is drawn onto a buffer of cells (one per grapheme, along with the style it's printed in) instead of being passed on.
+When the frame is flushed, the buffer is compared to the frame before it, and only the cells that changed are printed,
+with one MoveTo and Print for each run of changed cells in a line. This avoids repainting the whole terminal every frame,
+which flickers, and is slow over slow connections. Text printed outside of the frame is ignored.
+The first frame, and the first frame after invalidate() or resize(), is printed in full.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let frame = grid::Frame::new(0, 0, 10, 2);
+let mut output = FrameDiffer::new(Count::new(StringBuffer::from_frame(&frame)), &frame);
+let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+process.print(&mut output, &mut ())?;
+output.flush(&mut ())?;
+assert_eq!(output.handler.reset(), Stats { prints: 2, moves: 2, bytes: 20 });
+let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Some thing".to_string(), &mut Truncate, grid::Alignment::Plus);
+process.print(&mut output, &mut ())?;
+output.flush(&mut ())?;
+assert_eq!(output.handler.reset(), Stats { prints: 1, moves: 1, bytes: 5 });
+assert_eq!(output.handler.handler.trimmed_lines(), vec!["Some thing".to_string()]);
+# Ok(())
+# }
+```
+*/
+pub struct FrameDiffer<H: Handler> {
+    pub handler: H,
+    offset_x: usize,
+    offset_y: usize,
+    current: Vec<Vec<(String, Style)>>,
+    previous: Option<Vec<Vec<(String, Style)>>>,
+    current_x: usize,
+    current_y: usize,
+    style: Style,
+}
+impl<H: Handler> FrameDiffer<H> {
+    /// Wraps a handler, with a buffer covering the frame inputted.
+    pub fn new(handler: H, frame: &Frame) -> FrameDiffer<H> {
+        let grid = frame.next_frame();
+        FrameDiffer {
+            handler,
+            offset_x: grid.start_x,
+            offset_y: grid.start_y,
+            current: vec![vec![(" ".to_string(), Style::new()); grid.end_x - grid.start_x]; grid.end_y - grid.start_y],
+            previous: None,
+            current_x: grid.start_x,
+            current_y: grid.start_y,
+            style: Style::new(),
+        }
+    }
+    /// Resizes the buffer to cover the frame inputted. The buffer is cleared, and the next frame is printed in full.
+    pub fn resize(&mut self, frame: &Frame) {
+        let grid = frame.next_frame();
+        self.offset_x = grid.start_x;
+        self.offset_y = grid.start_y;
+        self.current = vec![vec![(" ".to_string(), Style::new()); grid.end_x - grid.start_x]; grid.end_y - grid.start_y];
+        self.previous = None;
+    }
+    /// Forgets what the last frame looked like, so the next frame is printed in full.
+    /// This is useful if something else might have drawn over the terminal.
+    pub fn invalidate(&mut self) {
+        self.previous = None;
+    }
+    /// Passes on every cell that changed since the last flush, and remembers the frame so the next frame can be compared to it.
+    /// The output device is left in the default style.
+    /// # Errors
+    /// Returns an error if the handler returns an error. The frame is still remembered, so the next frame is compared to it.
+    pub fn flush(&mut self, out: &mut H::OutputDevice) -> Result<(), H::Error> {
+        let previous = self.previous.replace(self.current.clone());
+        // The style the output device is in. Handlers that don't use styles only get the text.
+        let mut style = Style::new();
+        let styled = self.handler.uses_styles();
+        for (y, line) in self.current.iter().enumerate() {
+            let changed = |x: usize| previous.as_ref().map_or(true, |previous| previous[y][x] != line[x]);
+            let mut x = 0;
+            while x < line.len() {
+                if !changed(x) {
+                    x += 1;
+                    continue;
+                }
+                self.handler.handle(out, &Action::MoveTo(self.offset_x + x, self.offset_y + y))?;
+                let mut run = String::new();
+                while x < line.len() && changed(x) {
+                    let (text, cell_style) = &line[x];
+                    if *cell_style != style && styled {
+                        if !run.is_empty() {
+                            self.handler.handle(out, &Action::Print(&run))?;
+                        }
+                        self.handler.handle(out, &Action::SetStyle(*cell_style))?;
+                        run.clear();
+                        style = *cell_style;
+                    }
+                    run.push_str(text);
+                    x += 1;
+                }
+                self.handler.handle(out, &Action::Print(&run))?;
+            }
+        }
+        if style != Style::new() {
+            self.handler.handle(out, &Action::SetStyle(Style::new()))?;
+        }
+        Ok(())
+    }
+}
+impl<H: Handler> Handler for FrameDiffer<H> {
+    type OutputDevice = H::OutputDevice;
+    type Error = H::Error;
+    fn handle(&mut self, _: &mut Self::OutputDevice, input: &Action) -> Result<(), Self::Error> {
+        match input {
+            Action::Print(v) => {
+                let y = self.current_y.wrapping_sub(self.offset_y);
+                for grapheme in v.graphemes(true) {
+                    // Wide graphemes take up several cells. The cells after the first one are left empty.
+                    let width = trim::grapheme_width(grapheme).max(1);
+                    for i in 0..width {
+                        let x = (self.current_x + i).wrapping_sub(self.offset_x);
+                        if let Some(cell) = self.current.get_mut(y).and_then(|line| line.get_mut(x)) {
+                            *cell = (if i == 0 { grapheme.to_string() } else { String::new() }, self.style);
+                        }
+                    }
+                    self.current_x += width;
+                }
+            }
+            Action::MoveTo(x, y) => {
+                self.current_x = *x;
+                self.current_y = *y;
+            }
+            Action::SetStyle(style) => self.style = *style,
+        }
+        Ok(())
+    }
+}
@@ -1,9 +1,247 @@
-use crate::{grid::{Grid, Alignment, DividerStrategy}, out::{Action, Handler, SafeHandler}, trim::{TrimmedText, FormatError, TrimStrategy}};
+use std::{collections::{hash_map::DefaultHasher, HashMap, VecDeque}, fmt::Display, hash::{Hash, Hasher}};
+
+use crate::{grid::{Grid, Alignment, DividerStrategy, HorizontalAlignment}, out::{Action, Clip, Handler, SafeHandler, StringBuffer}, style::{self, Style}, trim::{self, TrimmedText, FormatError, TrimStrategy, TextMeasure, TruncateWithEllipsis}};
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum InternalFormatError {
     NoSpace(TrimmedText),
+    TooWide(TrimmedText),
+}
+/// The ruler's pattern, which repeats every hundred columns. Row numbers are taken from the digits in it.
+const RULER: &str = "0....:....1....:....2....:....3....:....4....:....5....:....6....:....7....:....8....:....9....:....";
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// The content of a process at some point in time.
+struct Snapshot {
+    divider: usize,
+    centered: bool,
+    minus: Vec<TrimmedText>,
+    plus: Vec<TrimmedText>,
+    nested: Vec<DrawProcess>,
+}
+/// The result of adding several lines at once with DrawProcess::add_lines_summary().
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LinesSummary<T: TrimStrategy> {
+    /// The number of lines that were added.
+    pub added: usize,
+    /// The lines that couldn't be added, in the order they were inputted.
+    pub rejected: Vec<T::Input>,
+}
+#[doc(hidden)]
+/// Adds the actions needed to print a line, changing the style wherever the line's styles say to.
+/// The line starts and ends in the default style. Unstyled lines, and lines printed without styles, are printed all at once.
+fn push_line<'a>(result: &mut Vec<Action<'a>>, line: &'a TrimmedText, styled: bool) {
+    let text = line.text.as_str();
+    if line.styles.is_empty() || !styled {
+        result.push(Action::Print(text));
+        return;
+    }
+    let mut styles = line.styles.iter().map(|(offset, style)| (style::boundary(text, *offset), *style)).collect::<Vec<_>>();
+    styles.sort_by_key(|(offset, _)| *offset);
+    if styles[0].0 > 0 {
+        result.push(Action::Print(&text[..styles[0].0]));
+    }
+    let mut current = Style::new();
+    for (i, (start, style)) in styles.iter().enumerate() {
+        let end = styles.get(i + 1).map_or(text.len(), |next| next.0);
+        if *start < end {
+            result.push(Action::SetStyle(*style));
+            result.push(Action::Print(&text[*start..end]));
+            current = *style;
+        }
+    }
+    if current != Style::new() {
+        result.push(Action::SetStyle(Style::new()));
+    }
+}
+/// Generates the text shown in a gutter next to a line of content. It's given the side of the gutter (left = minus),
+/// the number of lines that were already in the line's section, and the line itself. For more information, see DrawProcess::reserve_left().
+pub type GutterProvider = fn(Alignment, usize, &TrimmedText) -> String;
+/// The number of processes cloned since the count was last reset.
+#[cfg(feature = "clone-counter")]
+static CLONES: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+/**
+Gets the number of times a DrawProcess has been cloned since the count was last reset (including processes nested inside of cloned ones).
+Only available with the clone-counter feature, which is meant for debugging: resetting the count every frame shows
+whether processes are being cloned on a hot path.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::process::{clone_count, reset_clone_count};
+# fn main() {
+let process = grid::Frame::new(0, 0, 10, 2).next_frame().into_process(grid::DividerStrategy::Beginning);
+reset_clone_count();
+let copy = process.clone();
+assert_eq!(clone_count(), 1);
+assert_eq!(reset_clone_count(), 1);
+assert_eq!(clone_count(), 0);
+# }
+```
+*/
+#[cfg(feature = "clone-counter")]
+pub fn clone_count() -> usize {
+    CLONES.load(std::sync::atomic::Ordering::Relaxed)
+}
+/// Resets the number of times a DrawProcess has been cloned back to zero, returning the count before the reset.
+/// Only available with the clone-counter feature. For more information, see clone_count().
+#[cfg(feature = "clone-counter")]
+pub fn reset_clone_count() -> usize {
+    CLONES.swap(0, std::sync::atomic::Ordering::Relaxed)
+}
+/// Counts clones of the process it's part of, if the clone-counter feature is enabled. Otherwise, it does nothing.
+/// It has no effect on comparisons or hashing.
+#[derive(Debug, Default)]
+struct CloneCounter;
+impl Clone for CloneCounter {
+    fn clone(&self) -> Self {
+        #[cfg(feature = "clone-counter")]
+        CLONES.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        CloneCounter
+    }
+}
+impl PartialEq for CloneCounter {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl Eq for CloneCounter {}
+impl Hash for CloneCounter {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+/// The content hash of a process when it was last printed by print_if_changed(), if it hasn't changed since.
+/// It has no effect on comparisons or hashing, so printing a process doesn't make it different from an identical one.
+#[derive(Debug, Clone, Copy, Default)]
+struct Printed(Option<u64>);
+impl PartialEq for Printed {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl Eq for Printed {}
+impl Hash for Printed {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+/// The gutter provider of a process. Function pointers can't be compared reliably, so it has no effect on comparisons or hashing.
+/// The gutters it fills in are part of the content, so processes with different gutters still differ.
+#[derive(Debug, Clone, Copy, Default)]
+struct Gutter(Option<GutterProvider>);
+impl PartialEq for Gutter {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl Eq for Gutter {}
+impl Hash for Gutter {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+/// The grid a process was handed out for by a ProcessPool, which it's filed under when it's handed back.
+/// It has no effect on comparisons or hashing, so a process from a pool is the same as an identical new one.
+#[derive(Debug, Clone, Default)]
+struct Pooled(Option<Grid>);
+impl PartialEq for Pooled {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl Eq for Pooled {}
+impl Hash for Pooled {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+/// The characters a border is drawn with. For more information, see Grid::into_bordered_process().
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BorderStyle {
+    /// Thin lines with square corners (┌─┐).
+    Plain,
+    /// Thin lines with rounded corners (╭─╮).
+    Rounded,
+    /// Double lines (╔═╗).
+    Double,
+    /// Thick lines (┏━┓).
+    Thick,
+    /// Plain ASCII characters (+-+), for terminals that can't show box-drawing characters.
+    Ascii,
+}
+impl BorderStyle {
+    #[doc(hidden)]
+    /// Gets the horizontal line, vertical line, and the top left, top right, bottom left and bottom right corners.
+    fn parts(self) -> [&'static str; 6] {
+        match self {
+            BorderStyle::Plain => ["─", "│", "┌", "┐", "└", "┘"],
+            BorderStyle::Rounded => ["─", "│", "╭", "╮", "╰", "╯"],
+            BorderStyle::Double => ["═", "║", "╔", "╗", "╚", "╝"],
+            BorderStyle::Thick => ["━", "┃", "┏", "┓", "┗", "┛"],
+            BorderStyle::Ascii => ["-", "|", "+", "+", "+", "+"],
+        }
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A border drawn around a process, with the lines it's drawn with worked out ahead of time.
+struct Border {
+    style: BorderStyle,
+    outer: Grid,
+    top: String,
+    bottom: String,
+}
+impl Border {
+    #[doc(hidden)]
+    /// Creates a border around the grid inputted, without a title.
+    fn new(style: BorderStyle, outer: Grid) -> Border {
+        let [horizontal, _, _, _, bottom_left, bottom_right] = style.parts();
+        let width = outer.end_x - outer.start_x;
+        let mut border = Border {
+            style,
+            bottom: format!("{}{}{}", bottom_left, horizontal.repeat(width - 2), bottom_right),
+            top: String::new(),
+            outer,
+        };
+        border.set_title("", HorizontalAlignment::Left);
+        border
+    }
+    #[doc(hidden)]
+    /// Redraws the top line, with the title embedded in it. There's always at least one horizontal line on either side
+    /// of the title, and titles that don't fit end with an ellipsis.
+    fn set_title(&mut self, title: &str, alignment: HorizontalAlignment) {
+        let [horizontal, _, top_left, top_right, _, _] = self.style.parts();
+        let space = self.outer.end_x - self.outer.start_x - 2;
+        let room = Grid {
+            start_x: 0,
+            start_y: 0,
+            end_x: space.saturating_sub(2),
+            end_y: 1,
+        };
+        let title = match room.end_x {
+            0 => String::new(),
+            _ => TruncateWithEllipsis::default().trim(title.to_string(), &DrawProcess::new(room, DividerStrategy::Beginning), Alignment::Plus)[0]
+                .unpadded()
+                .to_string(),
+        };
+        let left_over = space - trim::width(&title);
+        let lead = match alignment {
+            _ if title.is_empty() => 0,
+            HorizontalAlignment::Left => 1,
+            HorizontalAlignment::Center => left_over / 2,
+            HorizontalAlignment::Right => left_over - 1,
+        };
+        let fill = left_over - lead;
+        self.top = format!("{}{}{}{}{}", top_left, horizontal.repeat(lead), title, horizontal.repeat(fill), top_right);
+    }
+    #[doc(hidden)]
+    /// Adds the actions that draw the border.
+    fn grab_actions<'a>(&'a self, result: &mut Vec<Action<'a>>) {
+        let vertical = self.style.parts()[1];
+        result.push(Action::MoveTo(self.outer.start_x, self.outer.start_y));
+        result.push(Action::Print(&self.top));
+        for y in self.outer.start_y + 1..self.outer.end_y - 1 {
+            result.push(Action::MoveTo(self.outer.start_x, y));
+            result.push(Action::Print(vertical));
+            result.push(Action::MoveTo(self.outer.end_x - 1, y));
+            result.push(Action::Print(vertical));
+        }
+        result.push(Action::MoveTo(self.outer.start_x, self.outer.end_y - 1));
+        result.push(Action::Print(&self.bottom));
+    }
 }
 /// A structure that can display text inside a grid.  
 /// Cloning chunk processes is bad practice! Use it only if you have to.  
@@ -14,28 +252,86 @@ pub struct DrawProcess {
     end_x: usize,
     end_y: usize,
     divider: usize,
+    centered: bool,
     minus: Vec<TrimmedText>,
     plus: Vec<TrimmedText>,
+    background: Vec<TrimmedText>,
+    background_alignment: Alignment,
+    nested: Vec<DrawProcess>,
+    history: VecDeque<Snapshot>,
+    future: Vec<Snapshot>,
+    history_limit: usize,
+    check_width: bool,
+    ruler: bool,
+    printed: Printed,
+    pooled: Pooled,
+    cursor_park: Option<(usize, usize)>,
+    gutters: (usize, usize),
+    gutter_provider: Gutter,
+    border: Option<Border>,
+    clones: CloneCounter,
     example_str: String,
 }
 impl DrawProcess {
     #[doc(hidden)]
     /// Creates a new chunk process.
     pub(crate) fn new(val: Grid, strategy: DividerStrategy) -> DrawProcess {
+        let example_str = " ".repeat(val.end_x - val.start_x);
+        DrawProcess::with_blank_line(val, strategy, example_str)
+    }
+    #[doc(hidden)]
+    /// Creates a new chunk process inside a border drawn around the grid. The grid has to be at least 3 by 3.
+    pub(crate) fn bordered(outer: Grid, strategy: DividerStrategy, style: BorderStyle) -> DrawProcess {
+        let inner = Grid {
+            start_x: outer.start_x + 1,
+            start_y: outer.start_y + 1,
+            end_x: outer.end_x - 1,
+            end_y: outer.end_y - 1,
+        };
+        DrawProcess {
+            border: Some(Border::new(style, outer)),
+            ..DrawProcess::new(inner, strategy)
+        }
+    }
+    #[doc(hidden)]
+    /// Gets where the divider starts out in a process of the height inputted.
+    fn divider_position(height: usize, strategy: &DividerStrategy) -> usize {
+        match strategy {
+            DividerStrategy::Beginning => 0,
+            DividerStrategy::End => height,
+            DividerStrategy::Halfway => height / 2,
+            DividerStrategy::Pos(v) => *v,
+            DividerStrategy::Centered => height / 2,
+        }
+    }
+    #[doc(hidden)]
+    /// Creates a new chunk process, using a blank line that's already the right width.
+    fn with_blank_line(val: Grid, strategy: DividerStrategy, example_str: String) -> DrawProcess {
         DrawProcess {
             start_x: val.start_x,
             start_y: val.start_y,
             end_x: val.end_x,
             end_y: val.end_y,
-            divider: match strategy {
-                DividerStrategy::Beginning => 0,
-                DividerStrategy::End => val.end_y - val.start_y,
-                DividerStrategy::Halfway => (val.end_y - val.start_y) / 2,
-                DividerStrategy::Pos(v) => v,
-            },
+            divider: DrawProcess::divider_position(val.end_y - val.start_y, &strategy),
+            centered: matches!(strategy, DividerStrategy::Centered),
             minus: Vec::new(),
             plus: Vec::new(),
-            example_str: " ".chars().cycle().take(val.end_x - val.start_x).collect(),
+            background: Vec::new(),
+            background_alignment: Alignment::Minus,
+            nested: Vec::new(),
+            history: VecDeque::new(),
+            future: Vec::new(),
+            history_limit: 0,
+            check_width: false,
+            ruler: false,
+            printed: Printed(None),
+            pooled: Pooled(None),
+            cursor_park: None,
+            gutters: (0, 0),
+            gutter_provider: Gutter(None),
+            border: None,
+            clones: CloneCounter,
+            example_str,
         }
     }
     /// Gets the chunk's width - the number of characters that can be displayed on a line.
@@ -125,11 +421,122 @@ impl DrawProcess {
     pub fn end_y(&self) -> usize {
         self.end_y
     }
+    /**
+    Gets the grid the process occupies.
+    ``` rust
+    # use grid_ui::grid;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(30, 30, 100, 100).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    assert_eq!(process.grid(), grid::Grid {start_x: 30, start_y: 30, end_x: 100, end_y: 100});
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn grid(&self) -> Grid {
+        Grid {
+            start_x: self.start_x,
+            start_y: self.start_y,
+            end_x: self.end_x,
+            end_y: self.end_y,
+        }
+    }
+    /**
+    Gets the grid the process covers, including its border. Without a border, this is the same as grid().
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::process::BorderStyle;
+    # fn main() -> Result<(), ()>{
+    let grid = grid::Frame::new(0, 0, 10, 5).next_frame();
+    let process = grid.into_bordered_process(grid::DividerStrategy::Beginning, BorderStyle::Plain).map_err(|_| ())?;
+    assert_eq!(process.outer_grid(), grid::Grid {start_x: 0, start_y: 0, end_x: 10, end_y: 5});
+    assert_eq!(process.grid(), grid::Grid {start_x: 1, start_y: 1, end_x: 9, end_y: 4});
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn outer_grid(&self) -> Grid {
+        match &self.border {
+            Some(border) => border.outer.clone(),
+            None => self.grid(),
+        }
+    }
+    /**
+    Sets the title embedded in the process's border, and where it's placed along the top of the border.
+    Titles that don't fit are cut off, and end with an ellipsis. An empty title removes the title.
+    # Errors
+    Returns the title if the process doesn't have a border.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::process::BorderStyle;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let grid = grid::Frame::new(0, 0, 12, 3).next_frame();
+    let mut process = grid.into_bordered_process(grid::DividerStrategy::Beginning, BorderStyle::Rounded).map_err(|_| ())?;
+    process.set_title("Logs", grid::HorizontalAlignment::Left).map_err(|_| ())?;
+    process.add_to_section("Some stuff here".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(process.to_string(), "╭─Logs─────╮\n│Some stuff│\n╰──────────╯\n".to_string());
+    process.set_title("Logs", grid::HorizontalAlignment::Center).map_err(|_| ())?;
+    assert!(process.to_string().starts_with("╭───Logs───╮"));
+    process.set_title("Logs", grid::HorizontalAlignment::Right).map_err(|_| ())?;
+    assert!(process.to_string().starts_with("╭─────Logs─╮"));
+    process.set_title("Server logs", grid::HorizontalAlignment::Left).map_err(|_| ())?;
+    assert!(process.to_string().starts_with("╭─Server …─╮"));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_title(&mut self, title: &str, alignment: HorizontalAlignment) -> Result<(), String> {
+        match &mut self.border {
+            Some(border) => {
+                border.set_title(title, alignment);
+                Ok(())
+            }
+            None => Err(title.to_string()),
+        }
+    }
+    /**
+    Gets a line of blank space that's exactly as wide as the process. This is what's printed on lines without any content.
+    ``` rust
+    # use grid_ui::grid;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 5).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    assert_eq!(process.blank_line(), "     ");
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn blank_line(&self) -> &str {
+        &self.example_str
+    }
+    #[doc(hidden)]
+    /// Creates an empty process that's narrower than this one. Used by strategies that wrap other strategies,
+    /// so that the inner strategy trims text to the space left over.
+    pub(crate) fn narrowed(&self, by: usize) -> DrawProcess {
+        let mut grid = self.grid();
+        grid.end_x -= by.min(self.width());
+        DrawProcess::new(grid, DividerStrategy::Beginning)
+    }
     #[doc(hidden)]
     /// Trims a string using a trim strategy.
     fn trim<T: TrimStrategy>(&self, text: T::Input, b: &mut T, a: Alignment) -> Vec<TrimmedText> {
         b.trim(text, self, a)
     }
+    #[doc(hidden)]
+    /// Runs a function on the process content is trimmed to, which is narrower than this one if there are gutters.
+    fn with_content<R>(&self, f: impl FnOnce(&DrawProcess) -> R) -> R {
+        match self.gutters {
+            (0, 0) => f(self),
+            (left, right) => f(&self.narrowed(left + right)),
+        }
+    }
+    #[doc(hidden)]
+    /// Gets the width that content is trimmed to.
+    fn content_width(&self) -> usize {
+        self.width() - self.gutters.0 - self.gutters.1
+    }
     /**
     Adds multi-line content to the selection, using the inputted strategy inside the inputted alignment. Returns everything that can't fit.
     Note that the multi-line content goes top to bottom, even if Alignment::Minus is selected.
@@ -207,20 +614,54 @@ impl DrawProcess {
         I: DoubleEndedIterator,
         I: Iterator<Item = T::Input>,
     {
-        if matches!(section, Alignment::Minus) {
+        self.record();
+        let res = if matches!(section, Alignment::Minus) {
             let text = text.rev();
-            let mut res = text.map(|x| self.add_to_section(x, strategy, section)).collect::<Vec<_>>();
+            let mut res = text.map(|x| self.add_unrecorded(x, strategy, section)).collect::<Vec<_>>();
             if matches!(section, Alignment::Minus) {
                 res.reverse();
             }
             res
         } else {
-            let mut res = text.map(|x| self.add_to_section(x, strategy, section)).collect::<Vec<_>>();
+            let mut res = text.map(|x| self.add_unrecorded(x, strategy, section)).collect::<Vec<_>>();
             if matches!(section, Alignment::Minus) {
                 res.reverse();
             }
             res
+        };
+        self.settle();
+        res
+    }
+    /**
+    Adds multiple lines of content to the section, like add_to_section_lines(), but collects the results into a summary.
+    Everything that couldn't be added is kept together, so it can be shown somewhere else (for example, on the next page).
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() {
+    let mut process = grid::Frame::new(0, 0, 10, 2).next_frame().into_process(grid::DividerStrategy::Beginning);
+    let lines = vec!["First".to_string(), "Second".to_string(), "Third".to_string(), "Fourth".to_string()];
+    let summary = process.add_lines_summary(lines.into_iter(), &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(summary.added, 2);
+    assert_eq!(summary.rejected, vec!["Third".to_string(), "Fourth".to_string()]);
+    # }
+    ```
+    */
+    pub fn add_lines_summary<T, I>(&mut self, text: I, strategy: &mut T, section: Alignment) -> LinesSummary<T>
+    where
+        T: TrimStrategy,
+        I: DoubleEndedIterator,
+        I: Iterator<Item = T::Input>,
+    {
+        let mut summary = LinesSummary { added: 0, rejected: Vec::new() };
+        for res in self.add_to_section_lines(text, strategy, section) {
+            match res {
+                Ok(()) => summary.added += 1,
+                Err(FormatError::NoSpace(text)) | Err(FormatError::TooWide(text, _)) => summary.rejected.push(text),
+            }
         }
+        summary
     }
     /**
     Adds single-line content to the selection, using the inputted strategy inside the inputted alignment.
@@ -288,7 +729,15 @@ impl DrawProcess {
     ```
     */
     pub fn add_to_section<T: TrimStrategy>(&mut self, text: T::Input, strategy: &mut T, section: Alignment) -> Result<(), FormatError<T>> {
-        let text = self.trim(text, strategy, section);
+        self.record();
+        let res = self.add_unrecorded(text, strategy, section);
+        self.settle();
+        res
+    }
+    #[doc(hidden)]
+    /// Adds single-line content to the selection without recording it in the history.
+    fn add_unrecorded<T: TrimStrategy>(&mut self, text: T::Input, strategy: &mut T, section: Alignment) -> Result<(), FormatError<T>> {
+        let text = self.with_content(|chunk| strategy.trim(text, chunk, section));
         let mut i = text.into_iter();
         let error: InternalFormatError = loop {
             if let Some(val) = i.next() {
@@ -307,7 +756,11 @@ impl DrawProcess {
                 // Adds the text that couldn't be formatted back onto the start and collects them all.
                 let extras = Some(back).into_iter().chain(i).collect::<Vec<_>>();
                 // Adds the error.
-                Err(FormatError::NoSpace(strategy.back(extras, &self, section)))
+                Err(FormatError::NoSpace(self.with_content(|chunk| strategy.back(extras, chunk, section))))
+            }
+            InternalFormatError::TooWide(back) => {
+                let extras = Some(back).into_iter().chain(i).collect::<Vec<_>>();
+                Err(FormatError::TooWide(self.with_content(|chunk| strategy.back(extras, chunk, section)), self.content_width()))
             }
         }
     }
@@ -328,16 +781,544 @@ impl DrawProcess {
     # Ok(())
     # }
     ```
+    Only the content is cleared. Settings, such as the border, are kept:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::process::BorderStyle;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let grid = grid::Frame::new(0, 0, 6, 3).next_frame();
+    let mut process = grid.into_bordered_process(grid::DividerStrategy::Beginning, BorderStyle::Ascii).map_err(|_| ())?;
+    process.add_to_section("Text".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.clear(grid::DividerStrategy::Beginning);
+    assert_eq!(process.to_string(), "+----+\n|    |\n+----+\n".to_string());
+    # Ok(())
+    # }
+    ```
     */
     pub fn clear(&mut self, new_strategy: DividerStrategy) {
-        *self = DrawProcess::new(Grid {
-            start_x: self.start_x,
-            start_y: self.start_y,
-            end_x: self.end_x,
-            end_y: self.end_y
-        }, new_strategy);
+        self.record();
+        // Only the content is reset. Settings (such as the border and gutters) are kept.
+        self.minus.clear();
+        self.plus.clear();
+        self.nested.clear();
+        self.divider = DrawProcess::divider_position(self.height(), &new_strategy);
+        self.centered = matches!(new_strategy, DividerStrategy::Centered);
+        self.settle();
     } 
     /**
+    Sets how many changes to the process's content are remembered, so that they can be undone.
+    Adding content, shoving, clearing and nesting are all changes. A call to add_to_section_lines() counts as a single change.
+    Undoing a change restores nested processes (including their content) and the divider strategy, along with the content.
+    The history is off (set to 0) by default. Lowering the limit forgets the oldest changes.
+    Changing the size of the process (for example, with split_free_space() or extend()) forgets every change.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Ignore;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.set_history_limit(10);
+    process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
+    process.clear(grid::DividerStrategy::Beginning);
+    process.add_to_section("New stuff!".to_string(), &mut Ignore, grid::Alignment::Plus);
+    assert!(process.undo());
+    assert!(process.undo());
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Some stuff\n          \n".to_string(), output);
+    assert!(process.redo());
+    assert!(process.redo());
+    assert!(!process.redo());
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("New stuff!\n          \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    Changes that don't do anything (such as adding text that doesn't fit) aren't remembered, so they don't push older changes out:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Ignore;
+    # fn main() {
+    let mut grid = grid::Frame::new(0, 0, 10, 1).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.set_history_limit(1);
+    process.add_to_section("A".to_string(), &mut Ignore, grid::Alignment::Plus);
+    assert!(process.add_to_section("B".to_string(), &mut Ignore, grid::Alignment::Plus).is_err());
+    assert!(process.undo());
+    assert!(!process.undo());
+    # }
+    ```
+    Undoing a clear restores the divider strategy and nested processes:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Ignore;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 4, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.set_history_limit(10);
+    process.nest(grid::Grid {start_x: 0, start_y: 2, end_x: 4, end_y: 3}, grid::DividerStrategy::Beginning).map_err(|_| ())?;
+    process.nested(0).ok_or(())?.add_to_section("Nest".to_string(), &mut Ignore, grid::Alignment::Plus);
+    process.clear(grid::DividerStrategy::Centered);
+    assert!(process.undo());
+    process.add_to_section("Top".to_string(), &mut Ignore, grid::Alignment::Plus);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Top\n    \n    \nNest\n".to_string(), output);
+    assert!(process.undo());
+    assert!(process.undo());
+    assert!(!process.undo());
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_history_limit(&mut self, limit: usize) {
+        self.history_limit = limit;
+        let excess = self.history.len().saturating_sub(limit);
+        self.history.drain(..excess);
+        self.future.truncate(limit);
+    }
+    /**
+    Sets whether the process checks that every line added to it fits inside of it.
+    Strategies that don't trim text (like Ignore), or custom strategies with bugs, can produce lines that are too wide,
+    which breaks formatting and can cause a panic when the process is printed to a StringBuffer.
+    If the check is on, adding a line that's too wide returns FormatError::TooWide instead, and the line isn't added.
+    The check is off by default.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::{Ignore, FormatError};
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    assert!(process.add_to_section("This is too wide!".to_string(), &mut Ignore, grid::Alignment::Plus).is_ok());
+    process.set_width_check(true);
+    let e = process.add_to_section("This is too wide!".to_string(), &mut Ignore, grid::Alignment::Plus).unwrap_err();
+    assert!(matches!(e, FormatError::TooWide(text, 10) if text == "This is too wide!"));
+    assert_eq!(process.space_left(grid::Alignment::Plus), 1);
+    # Ok(())
+    # }
+    ```
+    The text itself is measured, so strategies that report the wrong width are caught too:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::process::DrawProcess;
+    # use grid_ui::trim::{FormatError, TrimStrategy, TrimmedText};
+    # fn main() {
+    #[derive(Debug)]
+    struct Liar;
+    impl std::fmt::Display for Liar {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Liar")
+        }
+    }
+    impl TrimStrategy for Liar {
+        type Input = String;
+        fn trim(&mut self, text: String, _: &DrawProcess, _: grid::Alignment) -> Vec<TrimmedText> {
+            vec![TrimmedText { width: 1, ..TrimmedText::new(text) }]
+        }
+        fn back(&mut self, text: Vec<TrimmedText>, _: &DrawProcess, _: grid::Alignment) -> String {
+            text[0].text.clone()
+        }
+    }
+    let mut process = grid::Frame::new(0, 0, 10, 2).next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.set_width_check(true);
+    let e = process.add_to_section("This is too wide!".to_string(), &mut Liar, grid::Alignment::Plus);
+    assert!(matches!(e, Err(FormatError::TooWide(_, 10))));
+    # }
+    ```
+    */
+    pub fn set_width_check(&mut self, check: bool) {
+        self.check_width = check;
+    }
+    /**
+    Sets whether a ruler is drawn along the edges of the process, which is useful for debugging alignment issues.
+    The top line shows a column ruler, with a digit marking every tenth column and a colon marking every fifth.
+    The first column of every other line shows the line's number, modulo 10.
+    The ruler is drawn on top of everything else in the process, including nested processes. It's off by default.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let frame = grid::Frame::new(0, 0, 12, 3);
+    let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("More stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.set_ruler(true);
+    let mut output = out::StringBuffer::from_frame(&frame);
+    process.print(&mut output, &mut ())?;
+    assert_eq!(vec!["0....:....1.".to_string(), "1ore stuff  ".to_string(), "2           ".to_string()], output.lines());
+    process.set_ruler(false);
+    let mut output = out::StringBuffer::from_frame(&frame);
+    process.print(&mut output, &mut ())?;
+    assert_eq!(vec!["Some stuff  ".to_string(), "More stuff  ".to_string(), "            ".to_string()], output.lines());
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_ruler(&mut self, ruler: bool) {
+        self.ruler = ruler;
+    }
+    /**
+    Sets where the cursor is left after the process is printed, relative to the process's top left corner.
+    Otherwise, the cursor is left wherever the last line printed ended, which depends on the content.
+    The cursor is moved there as the last action every time the process is printed. Nested processes' positions are ignored.
+    # Errors
+    Returns the position if it isn't inside the process. The previous position will be kept.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::*;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    struct LastMove(Option<(usize, usize)>);
+    impl SafeHandler for LastMove {
+        type OutputDevice = ();
+        fn safe_handle(&mut self, _: &mut (), input: &Action) {
+            if let Action::MoveTo(x, y) = input {
+                self.0 = Some((*x, *y));
+            }
+        }
+    }
+    let mut process = grid::Frame::new(5, 5, 15, 8).next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert!(process.set_cursor_park(Some((9, 2))).is_ok());
+    assert_eq!(process.set_cursor_park(Some((10, 2))), Err((10, 2)));
+    let mut handler = LastMove(None);
+    process.print(&mut handler, &mut ())?;
+    assert_eq!(handler.0, Some((14, 7)));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_cursor_park(&mut self, position: Option<(usize, usize)>) -> Result<(), (usize, usize)> {
+        if let Some((x, y)) = position {
+            if x >= self.width() || y >= self.height() {
+                return Err((x, y));
+            }
+        }
+        self.cursor_park = position;
+        Ok(())
+    }
+    #[doc(hidden)]
+    /// Gets the action that parks the cursor, if there is one.
+    fn park_action(&self) -> Option<Action<'static>> {
+        self.cursor_park.map(|(x, y)| Action::MoveTo(self.start_x + x, self.start_y + y))
+    }
+    /**
+    Reserves a gutter of the width inputted on the left side of the process. Content is trimmed to the space left over,
+    and the gutter next to each line is filled in by the gutter provider (see set_gutter_provider()) when the line is added,
+    so gutters (such as line numbers, or markers for changed lines) stay next to their lines when the content moves.
+    Gutters are blank if there's no provider. Changing a gutter only affects lines added afterwards.
+    # Errors
+    Returns the width if the gutters wouldn't leave any space for content. The previous gutter will be kept.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::{Split, TrimmedText};
+    # fn main() -> Result<(), ()>{
+    fn gutter(side: grid::Alignment, index: usize, line: &TrimmedText) -> String {
+        match side {
+            grid::Alignment::Minus => format!("{}|", index + 1),
+            grid::Alignment::Plus => if line.cut.is_some() { ">".to_string() } else { String::new() },
+        }
+    }
+    let mut process = grid::Frame::new(0, 0, 10, 3).next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.reserve_left(2).map_err(|_| ())?;
+    process.reserve_right(1).map_err(|_| ())?;
+    process.set_gutter_provider(Some(gutter));
+    process.add_to_section("Some stuff here".to_string(), &mut Split, grid::Alignment::Plus);
+    assert_eq!(process.to_string(), "1|Some st \n2|uff her \n3|e       \n".to_string());
+    assert_eq!(process.reserve_left(9), Err(9));
+    # Ok(())
+    # }
+    ```
+    Styled text stays styled in the same places, after the gutter:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::{Action, SafeHandler};
+    # use grid_ui::style::{Style, StyledText};
+    # use grid_ui::trim::{Styled, Truncate};
+    # fn main() -> Result<(), ()>{
+    struct Red(String, bool);
+    impl SafeHandler for Red {
+        type OutputDevice = ();
+        fn safe_handle(&mut self, _: &mut (), input: &Action) {
+            match input {
+                Action::SetStyle(style) => self.1 = *style != Style::new(),
+                Action::Print(text) if self.1 => self.0.push_str(text),
+                _ => {}
+            }
+        }
+    }
+    let mut process = grid::Frame::new(0, 0, 10, 1).next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.reserve_left(3).map_err(|_| ())?;
+    let text = StyledText::new().plain("ab").span("cd", Style::rgb(255, 0, 0));
+    process.add_to_section(text, &mut Styled(Truncate), grid::Alignment::Plus);
+    let mut handler = Red(String::new(), false);
+    process.print(&mut handler, &mut ())?;
+    assert_eq!(handler.0, "cd".to_string());
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn reserve_left(&mut self, width: usize) -> Result<(), usize> {
+        if width + self.gutters.1 >= self.width() {
+            return Err(width);
+        }
+        self.gutters.0 = width;
+        Ok(())
+    }
+    /// Reserves a gutter of the width inputted on the right side of the process. For more information, see reserve_left().
+    /// # Errors
+    /// Returns the width if the gutters wouldn't leave any space for content. The previous gutter will be kept.
+    pub fn reserve_right(&mut self, width: usize) -> Result<(), usize> {
+        if self.gutters.0 + width >= self.width() {
+            return Err(width);
+        }
+        self.gutters.1 = width;
+        Ok(())
+    }
+    /// Sets the function that fills in gutters. For more information, see reserve_left().
+    pub fn set_gutter_provider(&mut self, provider: Option<GutterProvider>) {
+        self.gutter_provider = Gutter(provider);
+    }
+    /// Undoes the last change to the process's content. Returns false if there's nothing to undo.
+    /// For more information, see set_history_limit().
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop_back() {
+            Some(snapshot) => {
+                let current = self.restore(snapshot);
+                self.future.push(current);
+                true
+            }
+            None => false,
+        }
+    }
+    /// Redoes the last change that was undone. Returns false if there's nothing to redo.
+    /// Any new change to the process's content makes the changes undone so far impossible to redo.
+    /// For more information, see set_history_limit().
+    pub fn redo(&mut self) -> bool {
+        match self.future.pop() {
+            Some(snapshot) => {
+                let current = self.restore(snapshot);
+                self.history.push_back(current);
+                true
+            }
+            None => false,
+        }
+    }
+    #[doc(hidden)]
+    /// Takes a snapshot of the content, if the history is on. This should be called before changing the content.
+    /// The oldest snapshot is only forgotten once settle() knows that the new one is kept.
+    fn record(&mut self) {
+        if self.history_limit == 0 {
+            return;
+        }
+        self.history.push_back(Snapshot {
+            divider: self.divider,
+            centered: self.centered,
+            minus: self.minus.clone(),
+            plus: self.plus.clone(),
+            nested: self.nested.clone(),
+        });
+    }
+    #[doc(hidden)]
+    /// Finishes a change started by record(). Changes that didn't do anything are forgotten.
+    fn settle(&mut self) {
+        if let Some(last) = self.history.back() {
+            let unchanged = last.divider == self.divider && last.centered == self.centered;
+            if unchanged && last.minus == self.minus && last.plus == self.plus && last.nested == self.nested {
+                self.history.pop_back();
+            } else {
+                self.future.clear();
+                if self.history.len() > self.history_limit {
+                    self.history.pop_front();
+                }
+            }
+        }
+    }
+    #[doc(hidden)]
+    /// Replaces the content with a snapshot, returning the content replaced.
+    fn restore(&mut self, snapshot: Snapshot) -> Snapshot {
+        Snapshot {
+            divider: std::mem::replace(&mut self.divider, snapshot.divider),
+            centered: std::mem::replace(&mut self.centered, snapshot.centered),
+            minus: std::mem::replace(&mut self.minus, snapshot.minus),
+            plus: std::mem::replace(&mut self.plus, snapshot.plus),
+            nested: std::mem::replace(&mut self.nested, snapshot.nested),
+        }
+    }
+    #[doc(hidden)]
+    /// Forgets every change. This should be called when the process is resized, as old content might not fit.
+    fn forget_history(&mut self) {
+        self.history.clear();
+        self.future.clear();
+    }
+    /**
+    Nests a new process inside of this one, occupying the region inputted. The region is relative to this process,
+    so a region starting at (0, 0) starts at the top left corner of this process.
+    Nested processes are drawn on top of this process's content whenever this process is printed.
+    Returns the index of the nested process, which can be used to access it with nested().
+    Nested processes are removed when the process is cleared. When the process is resized (see split_free_space() and extend()),
+    nested processes keep their place relative to its top edge, and the ones that no longer fit inside of it are removed.
+    # Errors
+    Returns an error if the region doesn't fit inside of this process. The region is given back.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let frame = grid::Frame::new(0, 0, 10, 3);
+    let mut output: out::StringBuffer = out::StringBuffer::from_frame(&frame);
+    let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("..........".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("..........".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let region = grid::Grid { start_x: 2, start_y: 1, end_x: 8, end_y: 3 };
+    let index = process.nest(region, grid::DividerStrategy::Beginning).map_err(|_| ())?;
+    let inner = process.nested(index).ok_or(())?;
+    inner.add_to_section("Inside".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.print(&mut output, &mut ())?;
+    assert_eq!(vec!["..........".to_string(), "..Inside..".to_string(), "          ".to_string()], output.lines());
+    assert!(process.nest(grid::Grid { start_x: 5, start_y: 0, end_x: 11, end_y: 1 }, grid::DividerStrategy::Beginning).is_err());
+    # Ok(())
+    # }
+    ```
+    Nested processes move along with the top of the process:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut process = grid::Frame::new(0, 0, 10, 4).next_frame().into_process(grid::DividerStrategy::End);
+    process.add_to_section("Bottom".to_string(), &mut Truncate, grid::Alignment::Minus);
+    let index = process.nest(grid::Grid { start_x: 0, start_y: 0, end_x: 6, end_y: 2 }, grid::DividerStrategy::Beginning).map_err(|_| ())?;
+    let free_space = process.split_free_space(grid::Alignment::Minus, None, Some(2)).ok_or(())?;
+    assert_eq!(free_space, grid::Grid { start_x: 0, start_y: 0, end_x: 10, end_y: 2 });
+    assert_eq!(process.nested(index).ok_or(())?.start_y(), 2);
+    assert_eq!(process.split_free_space(grid::Alignment::Minus, None, None), Some(grid::Grid { start_x: 0, start_y: 2, end_x: 10, end_y: 3 }));
+    assert_eq!(process.to_string(), "Bottom    \n".to_string());
+    assert!(process.nested(index).is_none());
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn nest(&mut self, region: Grid, strategy: DividerStrategy) -> Result<usize, Grid> {
+        if region.start_x > region.end_x || region.start_y > region.end_y || region.end_x > self.width() || region.end_y > self.height() {
+            return Err(region);
+        }
+        let region = Grid {
+            start_x: self.start_x + region.start_x,
+            start_y: self.start_y + region.start_y,
+            end_x: self.start_x + region.end_x,
+            end_y: self.start_y + region.end_y,
+        };
+        self.record();
+        self.nested.push(DrawProcess::new(region, strategy));
+        self.settle();
+        Ok(self.nested.len() - 1)
+    }
+    /// Gets a process nested inside of this one, using the index returned by nest().
+    /// Returns None if there's no nested process with that index.
+    pub fn nested(&mut self, index: usize) -> Option<&mut DrawProcess> {
+        self.nested.get_mut(index)
+    }
+    /**
+    Sets the background layer of the process. The background is low-priority text (such as a "no results" message)
+    that is only shown on lines where no other content has been added.
+    The background is anchored to the top of the process with Alignment::Minus, or to the bottom with Alignment::Plus,
+    and it's trimmed the same way as text added to that section. It replaces any previous background, and survives calls to clear().
+    # Errors
+    Returns an error if the background has more lines than the process does, or if one of its lines is too wide and the process checks widths.
+    The text will be returned, and the previous background will be kept.
+    # Examples
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.set_background("Nothing!".to_string(), &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("          \n          \nNothing!  \n".to_string(), output);
+    process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("More stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("Last line!".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Some stuff\nMore stuff\nLast line!\n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    A background that's too big:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Split;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 1).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    assert!(process.set_background("Too long!".to_string(), &mut Split, grid::Alignment::Minus).is_err());
+    # Ok(())
+    # }
+    ```
+    A background that wraps, anchored to the top:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Split;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.set_background("Top of it".to_string(), &mut Split, grid::Alignment::Minus).map_err(|_| ())?;
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Top o\nf it \n     \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_background<T: TrimStrategy>(&mut self, text: T::Input, strategy: &mut T, a: Alignment) -> Result<(), FormatError<T>> {
+        let mut text = self.trim(text, strategy, a);
+        if text.len() > self.height() {
+            return Err(FormatError::NoSpace(strategy.back(text, self, a)));
+        }
+        if self.check_width && text.iter().any(|line| line.width > self.width()) {
+            return Err(FormatError::TooWide(strategy.back(text, self, a), self.width()));
+        }
+        if matches!(a, Alignment::Minus) {
+            // Text trimmed for the minus section goes from the bottom up, but the background is stored from the top down.
+            text.reverse();
+        }
+        self.background = text;
+        self.background_alignment = a;
+        Ok(())
+    }
+    /// Removes the background layer of the process, if there is one.
+    pub fn clear_background(&mut self) {
+        self.background.clear();
+    }
+    #[doc(hidden)]
+    /// Gets the text shown on a blank line, which is part of the background if the line has one.
+    fn blank_or_background(&self, line: usize) -> &str {
+        let offset = line - self.start_y;
+        let index = match self.background_alignment {
+            Alignment::Minus => Some(offset),
+            Alignment::Plus => (offset + self.background.len()).checked_sub(self.height()),
+        };
+        match index.and_then(|i| self.background.get(i)) {
+            Some(line) => &line.text,
+            None => &self.example_str,
+        }
+    }
+    /**
     Gives up free space in the Y direction, producing a grid if there's free space to give up. 
     Will take up to max_taken lines of space. If max_taken is set to None, it will take up to the divider line. 
     Will leave at least min_left lines TOTAL (in either direction). Might leave some blank lines. 
@@ -356,6 +1337,23 @@ impl DrawProcess {
     # Ok(())
     # }
     ```
+    If the process has a border, the border is moved along with it, and the space returned is outside of the border:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::process::BorderStyle;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let grid = grid::Frame::new(0, 0, 8, 6).next_frame();
+    let mut process = grid.into_bordered_process(grid::DividerStrategy::Beginning, BorderStyle::Ascii).map_err(|_| ())?;
+    process.add_to_section("Text".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let free_space = process.split_free_space(grid::Alignment::Plus, None, None).ok_or(())?;
+    assert_eq!(free_space, grid::Grid{start_x: 0, start_y: 3, end_x: 8, end_y: 6});
+    assert_eq!(process.to_string(), "+------+\n|Text  |\n+------+\n".to_string());
+    assert!(process.extend(free_space).is_ok());
+    assert_eq!(process.outer_grid(), grid::Grid{start_x: 0, start_y: 0, end_x: 8, end_y: 6});
+    # Ok(())
+    # }
+    ```
     */
     pub fn split_free_space(&mut self, a: Alignment, min_left: Option<usize>, max_taken: Option<usize>) -> Option<Grid> {
         match a {
@@ -365,43 +1363,44 @@ impl DrawProcess {
                 if let Some(val) = min_left {
                     space_occupied = space_occupied.max(val);
                 }
-                let mut total_space = space.checked_sub(space_occupied).unwrap_or(0);
+                let mut total_space = space.saturating_sub(space_occupied);
                 if let Some(val) = max_taken {
                     total_space = total_space.min(val);
                 }
                 if total_space != 0 {
-                    self.start_y += total_space;
+                    let outer = self.outer_grid();
+                    self.move_vertical_edges(self.start_y + total_space, self.end_y);
+                    self.divider -= total_space;
+                    self.forget_history();
                     Some(Grid {
-                        start_x: self.start_x,
-                        start_y: self.start_y - total_space,
-                        end_x: self.end_x,
-                        end_y: self.end_y - total_space,
+                        start_x: outer.start_x,
+                        start_y: outer.start_y,
+                        end_x: outer.end_x,
+                        end_y: outer.start_y + total_space,
                     })
                 } else {
                     None
                 }
             },
             Alignment::Plus => {
-                println!("END: {} START: {} DIVIDE: {}", self.end_y, self.start_y, self.divider);
                 let space = self.end_y - self.start_y - self.divider;
-                println!("SPACE: {}", space);
                 let mut space_occupied = self.plus.len();
-                println!("OCCUPIED: {}", space_occupied);
                 if let Some(val) = min_left {
                     space_occupied = space_occupied.max(val);
                 }
-                let mut total_space = space.checked_sub(space_occupied).unwrap_or(0);
-                println!("TOTAL: {}", total_space);
+                let mut total_space = space.saturating_sub(space_occupied);
                 if let Some(val) = max_taken {
                     total_space = total_space.min(val);
                 }
                 if total_space != 0 {
-                    self.end_y -= total_space;
+                    let outer = self.outer_grid();
+                    self.move_vertical_edges(self.start_y, self.end_y - total_space);
+                    self.forget_history();
                     Some(Grid {
-                        start_x: self.start_x,
-                        start_y: self.end_y,
-                        end_x: self.end_x,
-                        end_y: self.end_y + total_space,
+                        start_x: outer.start_x,
+                        start_y: outer.end_y - total_space,
+                        end_x: outer.end_x,
+                        end_y: outer.end_y,
                     })
                 } else {
                     None
@@ -433,37 +1432,149 @@ impl DrawProcess {
     ```
     */
     pub fn extend(&mut self, grid: Grid) -> Result<(), Grid> {
-        if self.start_x == grid.start_x && self.end_x == grid.end_x {
-            if self.end_y == grid.start_y {
-                self.end_y = grid.end_y;
+        let outer = self.outer_grid();
+        let height = grid.end_y - grid.start_y;
+        if outer.start_x == grid.start_x && outer.end_x == grid.end_x {
+            if outer.end_y == grid.start_y {
+                self.move_vertical_edges(self.start_y, self.end_y + height);
+                self.recenter();
+                self.forget_history();
                 return Ok(())
             }
-            if self.start_y == grid.end_y {
-                self.start_y = grid.start_y;
+            if outer.start_y == grid.end_y {
+                self.move_vertical_edges(self.start_y - height, self.end_y);
+                self.recenter();
+                self.forget_history();
                 return Ok(())
             }
         }
         Err(grid)
     }
     #[doc(hidden)]
+    /// Moves the top and bottom edges of the process. If there's a border, it's moved along with them.
+    /// Nested processes are moved along with the top edge, and the ones that no longer fit are removed.
+    fn move_vertical_edges(&mut self, start_y: usize, end_y: usize) {
+        for process in self.nested.iter_mut() {
+            process.shift_vertically(self.start_y, start_y);
+        }
+        self.nested.retain(|process| process.outer_grid().end_y <= end_y);
+        self.start_y = start_y;
+        self.end_y = end_y;
+        if let Some(border) = &mut self.border {
+            border.outer.start_y = start_y - 1;
+            border.outer.end_y = end_y + 1;
+        }
+    }
+    #[doc(hidden)]
+    /// Moves the whole process (including its border and nested processes) up or down, so that a line at y = from ends up at y = to.
+    /// Every line of the process has to be at or below from.
+    fn shift_vertically(&mut self, from: usize, to: usize) {
+        self.start_y = self.start_y - from + to;
+        self.end_y = self.end_y - from + to;
+        if let Some(border) = &mut self.border {
+            border.outer.start_y = border.outer.start_y - from + to;
+            border.outer.end_y = border.outer.end_y - from + to;
+        }
+        for process in self.nested.iter_mut() {
+            process.shift_vertically(from, to);
+        }
+    }
+    /**
+    Gets the number of lines that can still be added to a section.
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Ignore;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 4).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Halfway);
+    process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
+    assert_eq!(process.space_left(grid::Alignment::Minus), 2);
+    assert_eq!(process.space_left(grid::Alignment::Plus), 1);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn space_left(&self, section: Alignment) -> usize {
+        if self.centered {
+            return self.end_y - self.start_y - self.minus.len() - self.plus.len();
+        }
+        match section {
+            Alignment::Minus => self.divider - self.minus.len(),
+            Alignment::Plus => self.end_y - self.start_y - self.divider - self.plus.len(),
+        }
+    }
+    /**
+    Gets the number of lines some text would take up if it was added using the strategy inputted, without adding it.
+    The text is measured (see TextMeasure) rather than trimmed, so strategies that keep track of what they've trimmed aren't changed.
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Split;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 4).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    assert_eq!(process.required_lines(&"Short".to_string(), &Split), 1);
+    assert_eq!(process.required_lines(&"This is a little too big..".to_string(), &Split), 3);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn required_lines<T: TextMeasure>(&self, text: &T::Input, strategy: &T) -> usize {
+        strategy.measure(text, self.content_width())
+    }
+    /**
+    Checks whether some text would fit in a section if it was added using the strategy inputted, without adding it.
+    This is useful for deciding between a full version and a summarized version of some text before adding either.
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Split;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    let full = "This is a little too big..".to_string();
+    let summary = "Too big!".to_string();
+    assert!(!process.can_fit(&full, &Split, grid::Alignment::Plus));
+    assert!(process.can_fit(&summary, &Split, grid::Alignment::Plus));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn can_fit<T: TextMeasure>(&self, text: &T::Input, strategy: &T, section: Alignment) -> bool {
+        self.required_lines(text, strategy) <= self.space_left(section)
+    }
+    #[doc(hidden)]
     /// Adds trimmed text to a section.
-    fn add_to_section_trimmed(&mut self, text: TrimmedText, section: Alignment) -> Result<(), InternalFormatError> {
+    fn add_to_section_trimmed(&mut self, mut text: TrimmedText, section: Alignment) -> Result<(), InternalFormatError> {
+        if self.space_left(section) == 0 {
+            return Err(InternalFormatError::NoSpace(text));
+        }
+        // The width is measured again, as strategies fill in TrimmedText::width themselves.
+        if self.check_width && trim::width(&text.text) > self.content_width() {
+            return Err(InternalFormatError::TooWide(text));
+        }
+        if self.gutters != (0, 0) {
+            let index = if matches!(section, Alignment::Minus) { self.minus.len() } else { self.plus.len() };
+            let (left, right) = match self.gutter_provider.0 {
+                Some(provider) => (provider(Alignment::Minus, index, &text), provider(Alignment::Plus, index, &text)),
+                None => (String::new(), String::new()),
+            };
+            text.decorate(&trim::pad(&left, self.gutters.0), &trim::pad(&right, self.gutters.1), self.content_width(), self.width());
+        }
         if matches!(section, Alignment::Minus) {
-            let space = self.divider - self.minus.len();
-            if space == 0 {
-                return Err(InternalFormatError::NoSpace(text));
-            }
             self.minus.push(text);
         } else {
-            let space = self.end_y - self.start_y - self.divider - self.plus.len();
-            if space == 0 {
-                return Err(InternalFormatError::NoSpace(text));
-            }
             self.plus.push(text);
         }
+        self.recenter();
         Ok(())
     }
     #[doc(hidden)]
+    /// Moves the divider so that the content is vertically centered, if the process is centered.
+    fn recenter(&mut self) {
+        if self.centered {
+            self.divider = self.minus.len() + (self.end_y - self.start_y - self.minus.len() - self.plus.len()) / 2;
+        }
+    }
+    #[doc(hidden)]
     /**
     Shoves the data in the positive or negative direction, changing the divider to make more space available on one side.
     Moving text to the bottom or top:
@@ -494,40 +1605,100 @@ impl DrawProcess {
     ```
     */
     pub fn shove(&mut self, direction: Alignment) {
+        self.record();
         match direction {
             Alignment::Minus => self.divider = self.divider.min(self.minus.len()),
             Alignment::Plus => self.divider = self.divider.max(self.end_y - self.start_y - self.plus.len()),
         }
+        self.settle();
+    }
+    /**
+    Shoves the data in the positive or negative direction, like shove(), but reports how much space was made.
+    Shoving in the negative direction makes space in the positive section, and vice versa.
+    Returns the number of lines of space made, or None if the divider couldn't move (meaning there's no space to make).
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Ignore;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 4).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Halfway);
+    process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Minus);
+    assert_eq!(process.try_shove(grid::Alignment::Minus), Some(1));
+    assert_eq!(process.try_shove(grid::Alignment::Minus), None);
+    assert_eq!(process.try_shove(grid::Alignment::Plus), Some(3));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn try_shove(&mut self, direction: Alignment) -> Option<usize> {
+        let divider = self.divider;
+        self.shove(direction);
+        match divider.max(self.divider) - divider.min(self.divider) {
+            0 => None,
+            moved => Some(moved),
+        }
     }
     #[doc(hidden)]
-    /// Transforms the board into actions.
-    fn grab_actions(&mut self) -> Vec<Action> {
+    /// Transforms the board into actions. Lines are printed without styles if styled is false.
+    fn grab_actions(&self, styled: bool) -> Vec<Action<'_>> {
         let mut result = Vec::new();
+        if let Some(border) = &self.border {
+            border.grab_actions(&mut result);
+        }
         let start_x = self.start_x;
         let start_y = self.start_y + self.divider - self.minus.len();
         let divider = self.start_y + self.divider;
         // Adds blank lines, making sure that the entirety of grid is clear.
         for i in self.start_y..start_y {
             result.push(Action::MoveTo(start_x, i));
-            result.push(Action::Print(&self.example_str));
+            result.push(Action::Print(self.blank_or_background(i)));
         }
         // Adds negative lines
         for (i, line) in self.minus.iter().rev().enumerate() {
             result.push(Action::MoveTo(start_x, start_y + i));
-            result.push(Action::Print(&line.0));
+            push_line(&mut result, line, styled);
         }
         // Adds positive lines
         for (i, line) in self.plus.iter().enumerate() {
             result.push(Action::MoveTo(start_x, divider + i));
-            result.push(Action::Print(&line.0));
+            push_line(&mut result, line, styled);
         }
         // Adds blank lines, making sure that the entirety of grid is clear.
         for i in self.start_y + self.divider + self.plus.len()..self.end_y {
             result.push(Action::MoveTo(start_x, i));
-            result.push(Action::Print(&self.example_str));
+            result.push(Action::Print(self.blank_or_background(i)));
+        }
+        // Adds nested processes on top of everything else.
+        for process in &self.nested {
+            result.extend(process.grab_actions(styled));
+        }
+        if self.ruler {
+            self.grab_ruler_actions(&mut result);
         }
         result
     }
+    #[doc(hidden)]
+    /// Adds the actions that draw the ruler, in blocks of ten columns.
+    fn grab_ruler_actions(&self, result: &mut Vec<Action<'_>>) {
+        if self.height() == 0 {
+            return;
+        }
+        for block in (0..self.width()).step_by(10) {
+            let start = block % RULER.len();
+            let len = (self.width() - block).min(10);
+            result.push(Action::MoveTo(self.start_x + block, self.start_y));
+            result.push(Action::Print(&RULER[start..start + len]));
+        }
+        if self.width() == 0 {
+            return;
+        }
+        for line in 1..self.height() {
+            let start = line % 10 * 10;
+            result.push(Action::MoveTo(self.start_x, self.start_y + line));
+            result.push(Action::Print(&RULER[start..start + 1]));
+        }
+    }
     /**
     Prints out the grid using a handler.
     # Errors
@@ -548,13 +1719,105 @@ impl DrawProcess {
     ```
     */
     pub fn print<H: Handler>(&mut self, handler: &mut H, out: &mut H::OutputDevice) -> Result<(), H::Error> {
-        let actions = self.grab_actions();
+        let actions = self.grab_actions(handler.uses_styles());
         for line in actions {
             handler.handle(out, &line)?;
         }
+        if let Some(park) = self.park_action() {
+            handler.handle(out, &park)?;
+        }
+        // What was printed isn't hashed here, so print_if_changed() prints the process again next time.
+        self.printed = Printed(None);
         Ok(())
     }
     /**
+    Prints out the grid using a handler, but only if what it shows has changed since it was last printed.
+    Returns true if it was printed. This is much cheaper than printing when nothing has changed, which makes it
+    useful for dashboards with many processes that are redrawn every frame.
+    If something else draws over the process (or the terminal is cleared), call forget_printed() so it's printed again.
+    Only this method keeps track of what was printed, so the process is always printed again after print() or print_safe().
+    # Errors
+    Returns an error if the handler returns an error.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut process = grid::Frame::new(0, 0, 10, 1).next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let unprinted = process.clone();
+    let mut output: String = String::new();
+    assert!(process.print_if_changed(&mut out::OutToString, &mut output)?);
+    assert_eq!(process, unprinted);
+    assert!(!process.print_if_changed(&mut out::OutToString, &mut output)?);
+    process.clear(grid::DividerStrategy::Beginning);
+    assert!(process.print_if_changed(&mut out::OutToString, &mut output)?);
+    process.forget_printed();
+    assert!(process.print_if_changed(&mut out::OutToString, &mut output)?);
+    assert_eq!("Some stuff\n          \n          \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn print_if_changed<H: Handler>(&mut self, handler: &mut H, out: &mut H::OutputDevice) -> Result<bool, H::Error> {
+        let hash = self.content_hash();
+        if self.printed.0 == Some(hash) {
+            return Ok(false);
+        }
+        self.print(handler, out)?;
+        self.printed = Printed(Some(hash));
+        Ok(true)
+    }
+    /// Forgets what the process looked like when it was last printed, so that print_if_changed() prints it again.
+    pub fn forget_printed(&mut self) {
+        self.printed = Printed(None);
+    }
+    /**
+    Gets a hash of what the process shows: its position, size and text, along with everything nested inside of it.
+    Processes that would print the same thing have the same hash.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() {
+    let frame = grid::Frame::new(0, 0, 10, 1);
+    let mut first = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+    let mut second = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+    first.set_history_limit(10);
+    assert_eq!(first.content_hash(), second.content_hash());
+    first.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert_ne!(first.content_hash(), second.content_hash());
+    second.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(first.content_hash(), second.content_hash());
+    first.set_cursor_park(Some((0, 0)));
+    assert_ne!(first.content_hash(), second.content_hash());
+    # }
+    ```
+    */
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash_content(&mut hasher);
+        hasher.finish()
+    }
+    #[doc(hidden)]
+    /// Hashes everything that affects what the process prints.
+    fn hash_content<H: Hasher>(&self, state: &mut H) {
+        self.grid().hash(state);
+        self.divider.hash(state);
+        self.minus.hash(state);
+        self.plus.hash(state);
+        self.background.hash(state);
+        self.background_alignment.hash(state);
+        self.ruler.hash(state);
+        self.border.hash(state);
+        self.cursor_park.hash(state);
+        self.nested.len().hash(state);
+        for process in &self.nested {
+            process.hash_content(state);
+        }
+    }
+    /**
     Prints safely - this method cannot return an error.
     # Panics
     This method panics when the handler panics.
@@ -576,9 +1839,133 @@ impl DrawProcess {
     ```
     */
     pub fn print_safe<H: SafeHandler>(&mut self, handler: &mut H, out: &mut H::OutputDevice) {
-        let actions = self.grab_actions();
+        let actions = self.grab_actions(handler.uses_styles());
         for line in actions {
             handler.safe_handle(out, &line);
         }
+        if let Some(park) = self.park_action() {
+            handler.safe_handle(out, &park);
+        }
+        self.printed = Printed(None);
+    }
+}
+/**
+Shows what the process would look like if it was printed, one line at a time, which is useful for debugging.
+Text that doesn't fit in the process is cut off.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::trim::Ignore;
+# fn main() {
+let mut process = grid::Frame::new(0, 0, 5, 2).next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
+assert_eq!(process.to_string(), "Some \n     \n".to_string());
+# }
+```
+*/
+impl Display for DrawProcess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let grid = self.outer_grid();
+        let mut buffer = Clip::new(StringBuffer::new(grid.start_x, grid.start_y, grid.end_x, grid.end_y), grid);
+        for action in self.grab_actions(false) {
+            let _ = buffer.handle(&mut (), &action);
+        }
+        for line in buffer.handler.lines() {
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+/**
+A pool of processes that can be reused, for programs that build every process from scratch each frame.
+Processes handed back to the pool at the end of a frame are handed out again, cleared, when a process for the same grid is requested.
+This saves on allocations, since a reused process keeps the memory it had already allocated.
+Reused processes are reset completely, as if they were new.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::process::ProcessPool;
+# use grid_ui::trim::Truncate;
+# fn main() {
+let frame = grid::Frame::new(0, 0, 10, 2);
+let mut pool = ProcessPool::new();
+for i in 0..3 {
+    let mut process = pool.get(frame.next_frame(), grid::DividerStrategy::Beginning);
+    assert_eq!(process.space_left(grid::Alignment::Plus), 2);
+    process.add_to_section(format!("Frame {}", i), &mut Truncate, grid::Alignment::Plus);
+    pool.put(process);
+}
+assert_eq!(pool.len(), 1);
+# }
+```
+*/
+#[derive(Debug, Clone, Default)]
+pub struct ProcessPool {
+    free: HashMap<Grid, Vec<DrawProcess>>,
+}
+impl ProcessPool {
+    /// Creates an empty pool.
+    pub fn new() -> ProcessPool {
+        ProcessPool::default()
+    }
+    /// Gets a blank process for the grid inputted, reusing one from the pool if possible.
+    pub fn get(&mut self, grid: Grid, strategy: DividerStrategy) -> DrawProcess {
+        let process = match self.free.get_mut(&grid).and_then(|x| x.pop()) {
+            Some(mut process) => {
+                let mut minus = std::mem::take(&mut process.minus);
+                let mut plus = std::mem::take(&mut process.plus);
+                minus.clear();
+                plus.clear();
+                let mut example_str = std::mem::take(&mut process.example_str);
+                if example_str.len() != grid.end_x - grid.start_x {
+                    example_str = " ".repeat(grid.end_x - grid.start_x);
+                }
+                DrawProcess {
+                    minus,
+                    plus,
+                    ..DrawProcess::with_blank_line(grid.clone(), strategy, example_str)
+                }
+            }
+            None => DrawProcess::new(grid.clone(), strategy),
+        };
+        DrawProcess { pooled: Pooled(Some(grid)), ..process }
+    }
+    /**
+    Hands a process back to the pool, so that it can be reused. Processes are filed under the grid they were handed out for,
+    even if they've been resized since. Processes that didn't come from the pool are filed under the grid they cover.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::process::ProcessPool;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let frame = grid::Frame::new(0, 0, 10, 4);
+    let mut pool = ProcessPool::new();
+    for _ in 0..3 {
+        let mut process = pool.get(frame.next_frame(), grid::DividerStrategy::Beginning);
+        process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+        process.split_free_space(grid::Alignment::Plus, None, None).ok_or(())?;
+        pool.put(process);
+    }
+    assert_eq!(pool.len(), 1);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn put(&mut self, process: DrawProcess) {
+        let grid = process.pooled.0.clone().unwrap_or_else(|| process.outer_grid());
+        self.free.entry(grid).or_default().push(process);
+    }
+    /// Gets the number of processes in the pool.
+    pub fn len(&self) -> usize {
+        self.free.values().map(|x| x.len()).sum()
+    }
+    /// Returns true if there are no processes in the pool.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Drops every process in the pool, freeing their memory.
+    pub fn clear(&mut self) {
+        self.free.clear();
     }
 }
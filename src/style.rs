@@ -0,0 +1,436 @@
+use std::{
+    fmt::Display,
+    ops::Range,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The levels of red, green and blue used by the 6x6x6 color cube in the 256-color palette (colors 16 to 231).
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+/// The 16 basic colors, as xterm shows them by default. Other terminals may show them differently.
+const BASIC: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+/// A color that text can be printed in.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Color {
+    /// The terminal's default color.
+    #[default]
+    Default,
+    /// A color from the 256-color palette. Colors 0 to 15 are the basic colors, which terminals often change,
+    /// 16 to 231 are a 6x6x6 color cube, and 232 to 255 are shades of gray.
+    Indexed(u8),
+    /// A truecolor color, made up of red, green and blue.
+    Rgb(u8, u8, u8),
+}
+impl Color {
+    /**
+    Gets the red, green and blue values of the color. Basic colors are given the values xterm uses by default.
+    Returns None for the default color, which depends on the terminal.
+    # Example
+    ``` rust
+    # use grid_ui::style::Color;
+    # fn main() {
+    assert_eq!(Color::Indexed(196).to_rgb(), Some((255, 0, 0)));
+    assert_eq!(Color::Indexed(244).to_rgb(), Some((128, 128, 128)));
+    assert_eq!(Color::Default.to_rgb(), None);
+    # }
+    ```
+    */
+    pub fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        match self {
+            Color::Default => None,
+            Color::Rgb(r, g, b) => Some((r, g, b)),
+            Color::Indexed(n @ 0..=15) => Some(BASIC[n as usize]),
+            Color::Indexed(n @ 16..=231) => {
+                let n = n - 16;
+                Some((CUBE[n as usize / 36], CUBE[n as usize / 6 % 6], CUBE[n as usize % 6]))
+            }
+            Color::Indexed(n) => {
+                let level = 8 + (n - 232) * 10;
+                Some((level, level, level))
+            }
+        }
+    }
+    /**
+    Converts the color to the closest color in the 256-color palette, for terminals that don't support truecolor.
+    Only the color cube and the shades of gray are used, since terminals often change the basic colors.
+    # Example
+    ``` rust
+    # use grid_ui::style::Color;
+    # fn main() {
+    assert_eq!(Color::Rgb(255, 0, 0).to_indexed(), Color::Indexed(196));
+    assert_eq!(Color::Rgb(250, 10, 5).to_indexed(), Color::Indexed(196));
+    assert_eq!(Color::Rgb(128, 128, 128).to_indexed(), Color::Indexed(244));
+    assert_eq!(Color::Rgb(0, 0, 0).to_indexed(), Color::Indexed(16));
+    assert_eq!(Color::Rgb(100, 140, 180).to_indexed(), Color::Indexed(67));
+    assert_eq!(Color::Indexed(9).to_indexed(), Color::Indexed(9));
+    # }
+    ```
+    */
+    pub fn to_indexed(self) -> Color {
+        let (r, g, b) = match self {
+            Color::Rgb(r, g, b) => (r, g, b),
+            other => return other,
+        };
+        let level = |v: u8| (0..6).min_by_key(|&i| (CUBE[i] as i32 - v as i32).abs()).unwrap_or(0);
+        let (ri, gi, bi) = (level(r), level(g), level(b));
+        let cube = 16 + 36 * ri + 6 * gi + bi;
+        let average = (r as usize + g as usize + b as usize) / 3;
+        let gray = 232 + ((average.saturating_sub(3)) / 10).min(23);
+        let distance = |n: usize| {
+            let (cr, cg, cb) = Color::Indexed(n as u8).to_rgb().unwrap_or((0, 0, 0));
+            let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+            d(cr, r) + d(cg, g) + d(cb, b)
+        };
+        if distance(gray) < distance(cube) {
+            Color::Indexed(gray as u8)
+        } else {
+            Color::Indexed(cube as u8)
+        }
+    }
+    /**
+    Converts the color to the closest of the 16 basic colors, for terminals that only support those.
+    # Example
+    ``` rust
+    # use grid_ui::style::Color;
+    # fn main() {
+    assert_eq!(Color::Rgb(250, 10, 5).to_basic(), Color::Indexed(9));
+    assert_eq!(Color::Indexed(22).to_basic(), Color::Indexed(0));
+    assert_eq!(Color::Default.to_basic(), Color::Default);
+    # }
+    ```
+    */
+    pub fn to_basic(self) -> Color {
+        let (r, g, b) = match self.to_rgb() {
+            Some(rgb) => rgb,
+            None => return self,
+        };
+        let distance = |(cr, cg, cb): (u8, u8, u8)| {
+            let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+            d(cr, r) + d(cg, g) + d(cb, b)
+        };
+        let index = (0..16).min_by_key(|&i| distance(BASIC[i])).unwrap_or(0);
+        Color::Indexed(index as u8)
+    }
+}
+/// The colors a terminal can show.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSupport {
+    /// Only the 16 basic colors.
+    Basic,
+    /// The 256-color palette.
+    Indexed,
+    /// Truecolor (any red, green and blue values).
+    Truecolor,
+}
+/// The attributes text can be printed with. Terminals that don't support an attribute ignore it.
+/// Reverse video swaps the foreground and background colors, which is useful for showing selections.
+/// Blinking text isn't shown as blinking if blinking is turned off (see set_blink_enabled()).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Attributes {
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+    pub reverse: bool,
+    pub blink: bool,
+}
+#[doc(hidden)]
+/// Whether blinking text is shown as blinking, which is shared by the whole program.
+static BLINK: AtomicBool = AtomicBool::new(true);
+/**
+Sets whether handlers show blinking text as blinking. Blinking can be distracting, or worse, for some users,
+so programs can offer a way to turn it off. Blinking text is printed without blinking while it's off, and everything else about
+its style is kept. This applies to the whole program, and it's on by default.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::style::{self, Style, StyledText};
+# use grid_ui::trim::{Styled, Truncate};
+# fn main() -> Result<(), std::io::Error>{
+let frame = grid::Frame::new(0, 0, 2, 1);
+let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section(StyledText::new().span("!!", Style::new().blink().reverse()), &mut Styled(Truncate), grid::Alignment::Plus);
+let mut output: Vec<u8> = Vec::new();
+process.print(&mut AnsiHandler::new(), &mut output)?;
+assert_eq!(String::from_utf8(output).unwrap(), "\x1b[1;1H\x1b[0;39;49;7;5m!!\x1b[0;39;49m".to_string());
+style::set_blink_enabled(false);
+let mut output: Vec<u8> = Vec::new();
+process.print(&mut AnsiHandler::new(), &mut output)?;
+assert_eq!(String::from_utf8(output).unwrap(), "\x1b[1;1H\x1b[0;39;49;7m!!\x1b[0;39;49m".to_string());
+assert!(!style::blink_enabled());
+# Ok(())
+# }
+```
+*/
+pub fn set_blink_enabled(enabled: bool) {
+    BLINK.store(enabled, Ordering::Relaxed);
+}
+/// Gets whether handlers show blinking text as blinking. For more information, see set_blink_enabled().
+pub fn blink_enabled() -> bool {
+    BLINK.load(Ordering::Relaxed)
+}
+/**
+The way text is printed: its foreground (text) color, its background color and its attributes.
+# Example
+``` rust
+# use grid_ui::style::{Color, Style};
+# fn main() {
+let style = Style::rgb(255, 128, 0).background(Color::Indexed(236)).bold();
+assert_eq!(style.foreground, Color::Rgb(255, 128, 0));
+assert_eq!(style.background, Color::Indexed(236));
+assert!(style.attributes.bold && !style.attributes.underline);
+assert_eq!(Style::indexed(208), Style::new().foreground(Color::Indexed(208)));
+# }
+```
+*/
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    pub foreground: Color,
+    pub background: Color,
+    pub attributes: Attributes,
+}
+impl Style {
+    /// Creates a style that uses the terminal's default colors.
+    pub fn new() -> Style {
+        Style::default()
+    }
+    /// Creates a style with a truecolor foreground.
+    pub fn rgb(r: u8, g: u8, b: u8) -> Style {
+        Style::new().foreground(Color::Rgb(r, g, b))
+    }
+    /// Creates a style with a foreground from the 256-color palette.
+    pub fn indexed(n: u8) -> Style {
+        Style::new().foreground(Color::Indexed(n))
+    }
+    /// Sets the foreground color.
+    pub fn foreground(mut self, color: Color) -> Self {
+        self.foreground = color;
+        self
+    }
+    /// Sets the background color.
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = color;
+        self
+    }
+    /// Makes the text bold.
+    pub fn bold(mut self) -> Self {
+        self.attributes.bold = true;
+        self
+    }
+    /// Makes the text dim.
+    pub fn dim(mut self) -> Self {
+        self.attributes.dim = true;
+        self
+    }
+    /// Makes the text italic.
+    pub fn italic(mut self) -> Self {
+        self.attributes.italic = true;
+        self
+    }
+    /// Underlines the text.
+    pub fn underline(mut self) -> Self {
+        self.attributes.underline = true;
+        self
+    }
+    /// Crosses the text out.
+    pub fn strikethrough(mut self) -> Self {
+        self.attributes.strikethrough = true;
+        self
+    }
+    /// Swaps the text's foreground and background colors (reverse video).
+    pub fn reverse(mut self) -> Self {
+        self.attributes.reverse = true;
+        self
+    }
+    /// Makes the text blink, unless blinking is turned off (see set_blink_enabled()).
+    pub fn blink(mut self) -> Self {
+        self.attributes.blink = true;
+        self
+    }
+    /// Converts both colors to the closest colors in the 256-color palette. See Color::to_indexed().
+    pub fn to_indexed(self) -> Style {
+        Style {
+            foreground: self.foreground.to_indexed(),
+            background: self.background.to_indexed(),
+            ..self
+        }
+    }
+    /// Converts both colors to the closest basic colors. See Color::to_basic().
+    pub fn to_basic(self) -> Style {
+        Style {
+            foreground: self.foreground.to_basic(),
+            background: self.background.to_basic(),
+            ..self
+        }
+    }
+}
+/// A piece of text printed in a single style.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub text: String,
+    pub style: Style,
+}
+impl Span {
+    /// Creates a span of text printed in the style inputted.
+    pub fn new(text: String, style: Style) -> Span {
+        Span { text, style }
+    }
+}
+/**
+Text made up of spans, each printed in its own style. Styles aren't stored as escape codes, so they don't count towards the width
+of the text. To add styled text to a process, use a strategy wrapped in trim::Styled.
+# Example
+``` rust
+# use grid_ui::style::{Style, StyledText};
+# fn main() {
+let text = StyledText::new().span("Error: ", Style::rgb(255, 0, 0).bold()).plain("file not found");
+assert_eq!(text.text(), "Error: file not found".to_string());
+assert_eq!(text.ranges(), vec![(0..7, Style::rgb(255, 0, 0).bold()), (7..21, Style::new())]);
+assert_eq!(StyledText::from_ranges(&text.text(), &text.ranges()), text);
+# }
+```
+*/
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct StyledText {
+    pub spans: Vec<Span>,
+}
+impl StyledText {
+    /// Creates text without any spans.
+    pub fn new() -> StyledText {
+        StyledText::default()
+    }
+    /// Adds a span of text printed in the style inputted.
+    pub fn span(mut self, text: &str, style: Style) -> Self {
+        self.spans.push(Span::new(text.to_string(), style));
+        self
+    }
+    /// Adds a span of text printed in the terminal's default style.
+    pub fn plain(self, text: &str) -> Self {
+        self.span(text, Style::new())
+    }
+    /**
+    Creates text with a horizontal gradient, going smoothly from the first color to the second across its graphemes
+    (for headers and progress bars). The style inputted is used for everything other than the foreground color.
+    A gradient needs truecolor, so on terminals that don't support it, the whole text is printed in the first color,
+    converted to the closest color the terminal supports. The default color can't be part of a gradient either,
+    so if either color is the default color, the whole text is printed in the first color.
+    # Example
+    ``` rust
+    # use grid_ui::style::{Color, ColorSupport, Style, StyledText};
+    # fn main() {
+    let text = StyledText::gradient("abcde", Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0), Style::new(), ColorSupport::Truecolor);
+    assert_eq!(text.spans.len(), 5);
+    assert_eq!(text.spans[2].style, Style::rgb(100, 50, 0));
+    assert_eq!(text.spans[4].style, Style::rgb(200, 100, 0));
+    let text = StyledText::gradient("abcde", Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255), Style::new().bold(), ColorSupport::Basic);
+    assert_eq!(text, StyledText::new().span("abcde", Style::indexed(9).bold()));
+    # }
+    ```
+    */
+    pub fn gradient(text: &str, from: Color, to: Color, style: Style, support: ColorSupport) -> StyledText {
+        let (start, end) = match (from.to_rgb(), to.to_rgb(), support) {
+            (Some(start), Some(end), ColorSupport::Truecolor) if !matches!(from, Color::Default) => (start, end),
+            _ => {
+                let color = match support {
+                    ColorSupport::Basic => from.to_basic(),
+                    ColorSupport::Indexed => from.to_indexed(),
+                    ColorSupport::Truecolor => from,
+                };
+                return StyledText::new().span(text, style.foreground(color));
+            }
+        };
+        let graphemes = text.graphemes(true).collect::<Vec<_>>();
+        let steps = graphemes.len().saturating_sub(1).max(1) as i32;
+        let mix = |a: u8, b: u8, i: usize| (a as i32 + (b as i32 - a as i32) * i as i32 / steps) as u8;
+        let mut res = StyledText::new();
+        for (i, grapheme) in graphemes.into_iter().enumerate() {
+            let color = Color::Rgb(mix(start.0, end.0, i), mix(start.1, end.1, i), mix(start.2, end.2, i));
+            match res.spans.last_mut() {
+                // Neighboring graphemes in the same color share a span.
+                Some(last) if last.style.foreground == color => last.text.push_str(grapheme),
+                _ => res = res.span(grapheme, style.foreground(color)),
+            }
+        }
+        res
+    }
+    /// Gets the text, without any styles.
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+    /// Gets the range of bytes in text() that each span covers, along with its style.
+    pub fn ranges(&self) -> Vec<(Range<usize>, Style)> {
+        let mut start = 0;
+        self.spans
+            .iter()
+            .map(|span| {
+                start += span.text.len();
+                (start - span.text.len()..start, span.style)
+            })
+            .collect()
+    }
+    /// Splits text back up into spans, using the ranges of bytes inputted (see ranges()). Text outside of every range is given the default style.
+    pub fn from_ranges(text: &str, ranges: &[(Range<usize>, Style)]) -> StyledText {
+        let mut res = StyledText::new();
+        let mut start = 0;
+        for (range, style) in ranges {
+            let end = boundary(text, range.end);
+            let range_start = boundary(text, range.start.max(start)).min(end);
+            if start < range_start {
+                res = res.plain(&text[start..range_start]);
+            }
+            if range_start < end {
+                res = res.span(&text[range_start..end], *style);
+            }
+            start = start.max(end);
+        }
+        if start < text.len() {
+            res = res.plain(&text[start..]);
+        }
+        res
+    }
+}
+impl From<String> for StyledText {
+    fn from(text: String) -> Self {
+        StyledText::new().plain(&text)
+    }
+}
+impl Display for StyledText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+#[doc(hidden)]
+/// Moves a byte offset back to the closest character boundary in the text, or to the end of the text.
+pub(crate) fn boundary(text: &str, offset: usize) -> usize {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
@@ -1,15 +1,31 @@
 use std::{
+    collections::HashMap,
     error::Error,
     fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    ops::Range,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        OnceLock, RwLock,
+    },
 };
 
 use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::UnicodeNormalization;
 
-use crate::{grid::Alignment, process::DrawProcess};
+use crate::{
+    grid::Alignment,
+    process::DrawProcess,
+    style::{Style, StyledText},
+};
 
 /// Represents a formatting problem. Contains the original inputted string, restored as close to its original glory as possible. 
 /// Note that some of the information in the string may be lost.
-/// Currently, there's only one variant of this error, indicating a lack of space.
+/// NoSpace indicates a lack of space. TooWide is only returned by processes that check widths (see DrawProcess::set_width_check()),
+/// and indicates that a line was wider than the process; it also contains the width of the process.
+/// More kinds of errors might be added in the future, so matching on a FormatError needs a wildcard arm.
 /// # Examples  
 /// ``` rust
 /// # use grid_ui::grid;
@@ -30,24 +46,296 @@ use crate::{grid::Alignment, process::DrawProcess};
 /// ```
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum FormatError<T: TrimStrategy> {
     NoSpace(T::Input),
+    TooWide(T::Input, usize),
 }
 impl<T: TrimStrategy> Display for FormatError<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             FormatError::NoSpace(value) => write!(f, "No space found for {}", value),
+            FormatError::TooWide(value, width) => write!(f, "{} is wider than {} columns", value, width),
         }
     }
 }
 impl<T: TrimStrategy> Error for FormatError<T> {}
 
+#[doc(hidden)]
+/// Whether there are any width overrides. This is checked first, so that measuring text doesn't need the lock unless there are.
+static OVERRIDDEN: AtomicBool = AtomicBool::new(false);
+#[doc(hidden)]
+/// Gets the table of width overrides, which is shared by the whole program.
+fn width_overrides() -> &'static RwLock<HashMap<String, usize>> {
+    static OVERRIDES: OnceLock<RwLock<HashMap<String, usize>>> = OnceLock::new();
+    OVERRIDES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+#[doc(hidden)]
+/// Runs a function with a way to measure graphemes, taking the lock on the width overrides (if there are any) only once.
+fn with_widths<R>(f: impl FnOnce(&dyn Fn(&str) -> usize) -> R) -> R {
+    if !OVERRIDDEN.load(Ordering::Acquire) {
+        return f(&natural_width);
+    }
+    let overrides = width_overrides().read().unwrap_or_else(|e| e.into_inner());
+    f(&|x| overrides.get(x).copied().unwrap_or_else(|| natural_width(x)))
+}
+/**
+Sets how many columns a grapheme takes up, overriding its usual width (see grapheme_width()).
+Terminals disagree on the width of some characters (such as powerline glyphs, or characters with an ambiguous width),
+so this can be used to correct text that's misrendered on a specific terminal.
+Overrides apply to the whole program, and are used by width() and anything that measures trimmed text.
+Text that was measured before an override was set isn't measured again: TrimmedText::width (and the way text was trimmed)
+keeps the old width, so text should be trimmed again after the overrides change.
+# Example
+``` rust
+# use grid_ui::trim::*;
+# fn main() {
+assert_eq!(width("\u{e0b0} main"), 6);
+set_width_override("\u{e0b0}", 2);
+assert_eq!(width("\u{e0b0} main"), 7);
+assert_eq!(TrimmedText::new("\u{e0b0}".to_string()).width, 2);
+clear_width_overrides();
+assert_eq!(width("\u{e0b0} main"), 6);
+# }
+```
+*/
+pub fn set_width_override(grapheme: &str, width: usize) {
+    width_overrides().write().unwrap_or_else(|e| e.into_inner()).insert(grapheme.to_string(), width);
+    OVERRIDDEN.store(true, Ordering::Release);
+}
+/// Removes every width override, so that every grapheme takes up its usual width again.
+pub fn clear_width_overrides() {
+    let mut overrides = width_overrides().write().unwrap_or_else(|e| e.into_inner());
+    overrides.clear();
+    OVERRIDDEN.store(false, Ordering::Release);
+}
+#[doc(hidden)]
+/// Gets the number of columns a grapheme takes up, ignoring overrides.
+fn natural_width(grapheme: &str) -> usize {
+    match grapheme.chars().next() {
+        None => 0,
+        // Variation selector 16 asks for an emoji to be shown as a (double-width) picture.
+        Some(_) if grapheme.contains('\u{fe0f}') => 2,
+        // The first character decides the width - the rest are combining marks, modifiers and joined characters.
+        // Control characters (which don't have a width) are counted as one column, like any other character.
+        Some(c) => c.width().unwrap_or(1),
+    }
+}
+/**
+Gets the number of columns a grapheme takes up in a terminal. Most graphemes take up one column,
+but wide characters (such as CJK characters and emoji) take up two, and combining marks on their own take up none.
+Overridden widths are used instead, if there are any (see set_width_override()).
+# Example
+``` rust
+# use grid_ui::trim::*;
+# fn main() {
+assert_eq!(grapheme_width("a"), 1);
+assert_eq!(grapheme_width("漢"), 2);
+assert_eq!(grapheme_width("👍🏽"), 2);
+assert_eq!(grapheme_width("e\u{301}"), 1);
+assert_eq!(width("漢字 ok"), 7);
+# }
+```
+*/
+pub fn grapheme_width(grapheme: &str) -> usize {
+    with_widths(|measure| measure(grapheme))
+}
+/// Gets the number of columns text takes up: the sum of the widths of its graphemes (see grapheme_width()).
+/// For more information, see set_width_override().
+pub fn width(text: &str) -> usize {
+    with_widths(|measure| text.graphemes(true).map(measure).sum())
+}
+#[doc(hidden)]
+/// Finds the end of the longest part of the start of the text that fits in the number of columns inputted, as a byte index.
+pub(crate) fn fit(text: &str, columns: usize) -> usize {
+    with_widths(|measure| fit_with(text, columns, measure))
+}
+#[doc(hidden)]
+/// Does the work of fit(), measuring graphemes with the function inputted.
+fn fit_with(text: &str, columns: usize, measure: &dyn Fn(&str) -> usize) -> usize {
+    let mut used = 0;
+    for (i, grapheme) in text.grapheme_indices(true) {
+        used += measure(grapheme);
+        if used > columns {
+            return i;
+        }
+    }
+    text.len()
+}
+#[doc(hidden)]
+/// Breaks text up into pieces that each fit in the number of columns inputted, as byte ranges.
+/// A grapheme that's too wide to fit at all gets a piece of its own, so that the text is always broken up.
+fn column_chunks(text: &str, columns: usize) -> Vec<Range<usize>> {
+    with_widths(|measure| {
+        let mut res = Vec::new();
+        let mut start = 0;
+        while start < text.len() {
+            let mut end = start + fit_with(&text[start..], columns, measure);
+            if end == start {
+                end += text[start..].graphemes(true).next().map_or(0, str::len);
+            }
+            res.push(start..end);
+            start = end;
+        }
+        res
+    })
+}
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
-/// Trimmed text is text that is marked as processed and displayable.
-/// It is only public so that users can create TrimStrategy objects other than the 3 provided.
-/// It is not meant to be manually be created by anything other than a TrimStrategy.
-pub struct TrimmedText(pub String);
+#[derive(Debug, Clone, Eq, Default)]
+/**
+Trimmed text is text that is marked as processed and displayable.
+It is only public so that users can create TrimStrategy objects other than the ones provided.
+It is not meant to be manually be created by anything other than a TrimStrategy.
+Along with the text displayed, it stores information about how it was trimmed, which can be used to restore the original text
+or to find out which part of the original text is displayed where.
+Custom strategies that don't keep track of any of this can simply use TrimmedText::new() (or from()).
+Two pieces of trimmed text are equal if the text displayed is the same.
+# Migrating
+TrimmedText used to be a tuple struct holding only the text displayed, which broke code that creates or reads it directly.
+TrimmedText(text) becomes TrimmedText::new(text) (or TrimmedText::from(text), or text.into()),
+and trimmed.0 becomes trimmed.text (or String::from(trimmed), to take the text out).
+``` rust
+# use grid_ui::trim::TrimmedText;
+let trimmed: TrimmedText = "Old".to_string().into();
+assert_eq!(trimmed, TrimmedText::new("Old".to_string()));
+assert_eq!(trimmed.text, "Old");
+assert_eq!(String::from(trimmed), "Old".to_string());
+```
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::trim::{Split, TrimStrategy, TrimmedText};
+# fn main() -> Result<(), ()>{
+let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+let v = Split.trim("This is a little too big..".to_string(), &process, grid::Alignment::Plus);
+assert_eq!(v[2].text, " big..    ".to_string());
+assert_eq!(v[2].width, 10);
+assert_eq!(v[2].pad, 4);
+assert_eq!(v[2].range, Some(20..26));
+assert_eq!(TrimmedText::from("Custom".to_string()).width, 6);
+# Ok(())
+# }
+```
+*/
+pub struct TrimmedText {
+    /// The text displayed.
+    pub text: String,
+    /// The width of the text displayed, in columns (see width()). This is kept up to date by new() and set_text(),
+    /// but not when width overrides change (see set_width_override()).
+    pub width: usize,
+    /// Text that was cut off to make this line fit, if there was any.
+    pub cut: Option<String>,
+    /// The number of bytes added to the end of the text: blank space added to fill the line,
+    /// and anything added by strategies that wrap other strategies (such as a suffix).
+    pub pad: usize,
+    /// The number of bytes added to the start of the text: blank space added (for example, to center it),
+    /// and anything added by strategies that wrap other strategies (such as a prefix or a line number).
+    pub lead: usize,
+    /// The range of bytes in the original text that this line displays, if it's known.
+    pub range: Option<Range<usize>>,
+    /// The styles the text is printed in, as the byte offsets in the text where each style starts.
+    /// Text before the first offset is printed in the terminal's default style.
+    pub styles: Vec<(usize, Style)>,
+}
+impl TrimmedText {
+    /// Creates trimmed text without any information about how it was trimmed.
+    pub fn new(text: String) -> TrimmedText {
+        TrimmedText {
+            width: width(&text),
+            text,
+            cut: None,
+            pad: 0,
+            lead: 0,
+            range: None,
+            styles: Vec::new(),
+        }
+    }
+    /// Creates trimmed text without any information about how it was trimmed, making sure that it fits in the width inputted.
+    /// # Errors
+    /// Returns the text if it's wider than the width inputted.
+    /// # Example
+    /// ``` rust
+    /// # use grid_ui::trim::TrimmedText;
+    /// assert!(TrimmedText::new_checked("Fits".to_string(), 4).is_ok());
+    /// assert_eq!(TrimmedText::new_checked("Too wide".to_string(), 4), Err("Too wide".to_string()));
+    /// ```
+    pub fn new_checked(text: String, width: usize) -> Result<TrimmedText, String> {
+        let res = TrimmedText::new(text);
+        if res.width > width {
+            Err(res.text)
+        } else {
+            Ok(res)
+        }
+    }
+    /// Replaces the text displayed, updating its width. Everything else is kept.
+    pub fn set_text(&mut self, text: String) {
+        self.width = width(&text);
+        self.text = text;
+    }
+    /// Gets the text displayed, without anything added to the start or the end of it (see lead and pad).
+    pub fn unpadded(&self) -> &str {
+        let end = self.text.len() - self.pad.min(self.text.len());
+        &self.text[self.lead.min(end)..end]
+    }
+    #[doc(hidden)]
+    /// Decorates the text: fits it to the inner width inputted, adds a prefix and a suffix, and fits the whole line to the width inputted.
+    /// The styles, lead and pad are moved to match. Used by strategies that wrap other strategies, and by processes with gutters.
+    pub(crate) fn decorate(&mut self, prefix: &str, suffix: &str, inner: usize, width: usize) {
+        let end = self.text.len() - self.pad.min(self.text.len());
+        let start = self.lead.min(end);
+        let kept = fit(&self.text, inner);
+        let text = pad(&format!("{}{}{}", prefix, pad(&self.text, inner), suffix), width);
+        let start = (prefix.len() + start.min(kept)).min(text.len());
+        let end = (prefix.len() + end.min(kept)).clamp(start, text.len());
+        self.move_styles(0, prefix.len());
+        self.lead = start;
+        self.pad = text.len() - end;
+        self.set_text(text);
+    }
+    #[doc(hidden)]
+    /// Undoes decorate(), given the width of the prefix and the inner width. The text is left padded to the inner width.
+    pub(crate) fn undecorate(&mut self, prefix: usize, inner: usize) {
+        let end = self.text.len() - self.pad.min(self.text.len());
+        let prefix = fit(&self.text, prefix);
+        let kept = fit(&self.text[prefix..], inner);
+        let text = self.text[prefix..prefix + kept].to_string();
+        let start = self.lead.saturating_sub(prefix).min(kept);
+        let end = end.saturating_sub(prefix).clamp(start, kept);
+        self.move_styles(prefix, 0);
+        self.lead = start;
+        self.pad = text.len() - end;
+        self.set_text(text);
+    }
+    /// Moves the styles so that the style at byte offset from starts at byte offset to instead, after text is added or removed
+    /// in front of it. Styles before from are moved to to.
+    pub fn move_styles(&mut self, from: usize, to: usize) {
+        for (offset, _) in self.styles.iter_mut() {
+            *offset = offset.saturating_sub(from) + to;
+        }
+    }
+}
+impl From<String> for TrimmedText {
+    fn from(text: String) -> Self {
+        TrimmedText::new(text)
+    }
+}
+impl From<TrimmedText> for String {
+    fn from(text: TrimmedText) -> Self {
+        text.text
+    }
+}
+impl PartialEq for TrimmedText {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.styles == other.styles
+    }
+}
+impl Hash for TrimmedText {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.text.hash(state);
+        self.styles.hash(state);
+    }
+}
 
 /// This trait is used for debug purposes.
 /// T implements DisplayAndDebug iff T implements Display and T implements Debug.
@@ -80,6 +368,23 @@ where
     /// This function generally shouldn't panic, and it should be marked clearly if it does.
     fn back(&mut self, text: Vec<TrimmedText>, _: &DrawProcess, a: Alignment) -> Self::Input;
 }
+/**
+A TextMeasure can work out how many lines a TrimStrategy would turn text into, without actually trimming it.
+This is much cheaper than trimming, so it's useful for deciding how big grids should be based on their content.
+# Example
+``` rust
+# use grid_ui::trim::*;
+# fn main() {
+assert_eq!(Split.measure(&"This is a little too big..".to_string(), 10), 3);
+assert_eq!(Truncate.measure(&"This is a little too big..".to_string(), 10), 1);
+# }
+```
+*/
+pub trait TextMeasure: TrimStrategy {
+    /// Gets the number of lines the text would take up in a grid of the width inputted.
+    /// This should always be the same as the number of lines trim() produces.
+    fn measure(&self, text: &Self::Input, width: usize) -> usize;
+}
 #[derive(Debug)]
 /// Useful for debug purposes, or for quick code. Bypasses the grid restrictions entirely.
 /// Does absolutely nothing to the text. This could potentially lead to bad formatting.
@@ -95,11 +400,11 @@ where
 /// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
 /// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
 /// let v = Ignore.trim("small".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("small".to_string())], v);
+/// assert_eq!(vec![TrimmedText::new("small".to_string())], v);
 /// let v = Ignore.trim("This fits.".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("This fits.".to_string())], v);
+/// assert_eq!(vec![TrimmedText::new("This fits.".to_string())], v);
 /// let v = Ignore.trim("This is a really long line that will break things in a terminal setup.".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("This is a really long line that will break things in a terminal setup.".to_string())], v);
+/// assert_eq!(vec![TrimmedText::new("This is a really long line that will break things in a terminal setup.".to_string())], v);
 /// # Ok(())
 /// # }
 /// ```
@@ -112,16 +417,25 @@ impl Display for Ignore {
 impl TrimStrategy for Ignore {
     type Input = String;
     fn trim(&mut self, text: String, _: &DrawProcess, _: Alignment) -> Vec<TrimmedText> {
-        vec![TrimmedText(text)]
+        vec![TrimmedText {
+            range: Some(0..text.len()),
+            ..TrimmedText::new(text)
+        }]
     }
 
     fn back(&mut self, text: Vec<TrimmedText>, _: &DrawProcess, _: Alignment) -> Self::Input {
-        text.into_iter().next().expect("Safe unwrap").0
+        text.into_iter().next().expect("Safe unwrap").text
+    }
+}
+impl TextMeasure for Ignore {
+    fn measure(&self, _: &String, _: usize) -> usize {
+        1
     }
 }
 #[derive(Debug)]
 /// The trim strategy cuts out anything that doesn't fit into the box in order to deal with grid restrictions.
 /// It also adds blank space to any short lines to make sure every bit of blank space is refreshed.
+/// The text cut out is kept, and the blank space added is removed, so the original text can be restored.
 /// # Example
 /// ``` rust
 /// # use grid_ui::grid;
@@ -133,11 +447,13 @@ impl TrimStrategy for Ignore {
 /// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
 /// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
 /// let v = Truncate.trim("small".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("small     ".to_string())], v);
+/// assert_eq!(vec![TrimmedText::new("small     ".to_string())], v);
+/// assert_eq!(Truncate.back(v, &process, grid::Alignment::Plus), "small".to_string());
 /// let v = Truncate.trim("This fits.".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("This fits.".to_string())], v);
+/// assert_eq!(vec![TrimmedText::new("This fits.".to_string())], v);
 /// let v = Truncate.trim("This is a really long line that will break things in a terminal setup.".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("This is a ".to_string())], v);
+/// assert_eq!(vec![TrimmedText::new("This is a ".to_string())], v);
+/// assert_eq!(Truncate.back(v, &process, grid::Alignment::Plus), "This is a really long line that will break things in a terminal setup.".to_string());
 /// # Ok(())
 /// # }
 /// ```
@@ -150,19 +466,216 @@ impl Display for Truncate {
 impl TrimStrategy for Truncate {
     type Input = String;
     fn trim(&mut self, text: String, chunk: &DrawProcess, _: Alignment) -> Vec<TrimmedText> {
-        let blank_space = " ".graphemes(true).cycle();
-        let res = text.graphemes(true).chain(blank_space).take(chunk.width()).collect();
-        vec![TrimmedText(res)]
+        let i = fit(&text, chunk.width());
+        if i < text.len() {
+            // The text doesn't fit, so the rest of it is cut off and kept.
+            // A wide grapheme that only half fits is cut off too, and replaced with blank space.
+            return vec![TrimmedText {
+                cut: Some(text[i..].to_string()),
+                range: Some(0..i),
+                ..padded(text[..i].to_string(), chunk.width())
+            }];
+        }
+        let range = Some(0..text.len());
+        vec![TrimmedText {
+            range,
+            ..padded(text, chunk.width())
+        }]
+    }
+    fn back(&mut self, text: Vec<TrimmedText>, _: &DrawProcess, _: Alignment) -> Self::Input {
+        let line = text.into_iter().next().expect("Safe unwrap");
+        let mut res = line.unpadded().to_string();
+        if let Some(cut) = line.cut {
+            res.push_str(&cut);
+        }
+        res
+    }
+}
+impl TextMeasure for Truncate {
+    fn measure(&self, _: &String, _: usize) -> usize {
+        1
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// This strategy works like Truncate, but lines that are cut off end with a marker ("…" by default),
+/// so it's clear that there's more text. This is useful for displaying file paths and URLs.
+/// The marker takes the place of the last graphemes that would have fit. Restoring text removes the marker.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::{TrimStrategy, TruncateWithEllipsis};
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let mut strategy = TruncateWithEllipsis::default();
+/// let v = strategy.trim("/usr/local/bin".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(v[0].text, "/usr/loca…".to_string());
+/// assert_eq!(strategy.back(v, &process, grid::Alignment::Plus), "/usr/local/bin".to_string());
+/// let v = strategy.trim("/usr".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(v[0].text, "/usr      ".to_string());
+/// let mut strategy = TruncateWithEllipsis::new("...");
+/// let v = strategy.trim("/usr/local/bin".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(v[0].text, "/usr/lo...".to_string());
+/// # Ok(())
+/// # }
+/// ```
+pub struct TruncateWithEllipsis {
+    marker: String,
+}
+impl TruncateWithEllipsis {
+    /// Creates a strategy that ends lines that are cut off with the marker inputted.
+    pub fn new(marker: &str) -> TruncateWithEllipsis {
+        TruncateWithEllipsis { marker: marker.to_string() }
+    }
+    #[doc(hidden)]
+    /// Gets the marker, cut off if it's wider than the width inputted.
+    fn marker(&self, width: usize) -> String {
+        self.marker[..fit(&self.marker, width)].to_string()
+    }
+}
+impl Default for TruncateWithEllipsis {
+    fn default() -> Self {
+        TruncateWithEllipsis::new("…")
+    }
+}
+impl Display for TruncateWithEllipsis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl TrimStrategy for TruncateWithEllipsis {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let width = chunk.width();
+        if self::width(&text) <= width {
+            return Truncate.trim(text, chunk, a);
+        }
+        // The text doesn't fit, so the end of it is replaced with the marker.
+        let marker = self.marker(width);
+        let i = fit(&text, width - self::width(&marker));
+        let res = format!("{}{}", &text[..i], marker);
+        let pad = width.saturating_sub(self::width(&res));
+        vec![TrimmedText {
+            cut: Some(text[i..].to_string()),
+            range: Some(0..i),
+            pad,
+            ..TrimmedText::new(format!("{}{}", res, " ".repeat(pad)))
+        }]
+    }
+    fn back(&mut self, text: Vec<TrimmedText>, chunk: &DrawProcess, _: Alignment) -> Self::Input {
+        let line = text.into_iter().next().expect("Safe unwrap");
+        match line.cut {
+            Some(ref cut) => {
+                let marker = self.marker(chunk.width());
+                let shown = line.unpadded();
+                let mut res = shown.strip_suffix(marker.as_str()).unwrap_or(shown).to_string();
+                res.push_str(cut);
+                res
+            }
+            None => line.unpadded().to_string(),
+        }
+    }
+}
+impl TextMeasure for TruncateWithEllipsis {
+    fn measure(&self, _: &String, _: usize) -> usize {
+        1
+    }
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// This strategy works like Truncate, but shows a window of the line that starts a number of columns in,
+/// so lines that are wider than the grid (such as minified code or log lines) can be scrolled through horizontally.
+/// Wide graphemes are never split: a wide grapheme that's only partly in the window is replaced with blank space.
+/// The text cut off on both sides is kept, and the blank space added is removed, so the original text can be restored.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::{HorizontalScroll, TrimStrategy};
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 6, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let mut strategy = HorizontalScroll::new(4);
+/// let v = strategy.trim("GET /index.html 200".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(v[0].text, "/index".to_string());
+/// assert_eq!(strategy.back(v, &process, grid::Alignment::Plus), "GET /index.html 200".to_string());
+/// strategy.scroll_by(-3);
+/// let v = strategy.trim("漢字漢字".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(v[0].text, " 字漢 ".to_string());
+/// assert_eq!(strategy.back(v, &process, grid::Alignment::Plus), "漢字漢字".to_string());
+/// # Ok(())
+/// # }
+/// ```
+pub struct HorizontalScroll {
+    /// The number of columns cut off the start of each line.
+    pub offset: usize,
+}
+impl HorizontalScroll {
+    /// Creates a strategy that cuts the number of columns inputted off the start of each line.
+    pub fn new(offset: usize) -> HorizontalScroll {
+        HorizontalScroll { offset }
+    }
+    /// Scrolls by the number of columns inputted: to the right if it's positive, and to the left if it's negative.
+    /// The offset never goes below 0.
+    pub fn scroll_by(&mut self, columns: isize) {
+        self.offset = if columns < 0 {
+            self.offset.saturating_sub(columns.unsigned_abs())
+        } else {
+            self.offset.saturating_add(columns as usize)
+        };
+    }
+}
+impl Display for HorizontalScroll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl TrimStrategy for HorizontalScroll {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, _: Alignment) -> Vec<TrimmedText> {
+        let width = chunk.width();
+        let (mut start, mut lead, mut column) = (text.len(), 0, 0);
+        for (i, grapheme) in text.grapheme_indices(true) {
+            if column >= self.offset {
+                start = i;
+                break;
+            }
+            column += grapheme_width(grapheme);
+            if column > self.offset {
+                // This grapheme is only partly in the window, so the part that is is replaced with blank space.
+                start = i + grapheme.len();
+                lead = (column - self.offset).min(width);
+                break;
+            }
+        }
+        let end = start + fit(&text[start..], width - lead);
+        let cut = format!("{}{}", &text[..start], &text[end..]);
+        vec![TrimmedText {
+            cut: if cut.is_empty() { None } else { Some(cut) },
+            lead,
+            range: Some(start..end),
+            ..padded(format!("{}{}", " ".repeat(lead), &text[start..end]), width)
+        }]
     }
     fn back(&mut self, text: Vec<TrimmedText>, _: &DrawProcess, _: Alignment) -> Self::Input {
-        text.into_iter().next().expect("Safe unwrap").0
+        let line = text.into_iter().next().expect("Safe unwrap");
+        let shown = line.unpadded();
+        match (&line.cut, &line.range) {
+            // The cut off text is made up of the text before the window, and then the text after it.
+            (Some(cut), Some(range)) => format!("{}{}{}", &cut[..range.start], shown, &cut[range.start..]),
+            (Some(cut), None) => format!("{}{}", shown, cut),
+            (None, _) => shown.to_string(),
+        }
+    }
+}
+impl TextMeasure for HorizontalScroll {
+    fn measure(&self, _: &String, _: usize) -> usize {
+        1
     }
 }
 #[derive(Debug)]
 /// This split splits the text into different lines, each of which fit just fine.
 /// It also adds blank space to any short lines to make sure every bit of blank space is refreshed.
-/// # Panics
-/// Panics if printing to a grid of 0 width.
+/// On a grid of 0 width, every grapheme gets a line of its own, so the lines overflow the grid.
 /// # Example
 /// ``` rust
 /// # use grid_ui::grid;
@@ -174,11 +687,30 @@ impl TrimStrategy for Truncate {
 /// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
 /// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
 /// let v = Split.trim("small".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("small     ".to_string())], v);
+/// assert_eq!(vec![TrimmedText::new("small     ".to_string())], v);
 /// let v = Split.trim("This fits.".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("This fits.".to_string())], v);
+/// assert_eq!(vec![TrimmedText::new("This fits.".to_string())], v);
 /// let v = Split.trim("This is a little too big..".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("This is a ".to_string()), TrimmedText("little too".to_string()), TrimmedText(" big..    ".to_string())], v);
+/// assert_eq!(vec![TrimmedText::new("This is a ".to_string()), TrimmedText::new("little too".to_string()), TrimmedText::new(" big..    ".to_string())], v);
+/// # Ok(())
+/// # }
+/// ```
+/// Restoring text in either direction removes the blank space added:
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::Split;
+/// # use grid_ui::trim::TrimStrategy;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// for a in [grid::Alignment::Plus, grid::Alignment::Minus] {
+///     let v = Split.trim("This is a little too big..".to_string(), &process, a);
+///     assert_eq!(Split.back(v, &process, a), "This is a little too big..".to_string());
+///     let v = Split.trim("small".to_string(), &process, a);
+///     assert_eq!(Split.back(v, &process, a), "small".to_string());
+///     let v = Split.trim(String::new(), &process, a);
+///     assert_eq!(Split.back(v, &process, a), String::new());
+/// }
 /// # Ok(())
 /// # }
 /// ```
@@ -191,28 +723,24 @@ impl Display for Split {
 impl TrimStrategy for Split {
     type Input = String;
     fn trim(&mut self, text: String, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
-        let mut v = text.graphemes(true).collect::<Vec<_>>();
-        if v.is_empty() {
-            v.push(" ");
-        } // An empty string won't create a line break unless we do this.
-          // Stores the previous value
-        let mut storage: &[&str] = &[];
-        // The trimmed text result
-        let mut res: Vec<TrimmedText> = Vec::new();
-        for line in v.chunks(chunk.width()) {
-            // each line, except for the last one, extends the entire grid. We only need to add extra blank space on the next one.
-            // As long as there's an item after, we don't need to extend the line with blank space.
-            if !storage.is_empty() {
-                res.push(TrimmedText(storage.iter().copied().collect::<String>()));
-            }
-            storage = line;
-        }
-        // Creates a cycle of blank space to extend the line with until the end of the chunk (to make sure no extra text from the chunk stays).
-        let blank_space = " ".graphemes(true).cycle();
-        // Adds a TrimmedText value of exactly the right visual length.
-        res.push(TrimmedText(
-            storage.iter().copied().chain(blank_space).take(chunk.width()).collect::<String>(),
-        ));
+        let width = chunk.width();
+        if text.is_empty() {
+            // An empty string still takes up a line.
+            return vec![TrimmedText {
+                range: Some(0..0),
+                ..padded(text, width)
+            }];
+        }
+        // The trimmed text result. Each line is extended with blank space until the end of the chunk
+        // (to make sure no extra text from the chunk stays). Only the last line is usually short, but lines are also
+        // short if a wide grapheme didn't fit at the end of them.
+        let mut res = column_chunks(&text, width)
+            .into_iter()
+            .map(|range| TrimmedText {
+                range: Some(range.clone()),
+                ..padded(text[range].to_string(), width)
+            })
+            .collect::<Vec<_>>();
         if matches!(a, Alignment::Minus) {
             // Reverses the direction if we're in the minus direction.
             res.reverse();
@@ -225,14 +753,1109 @@ impl TrimStrategy for Split {
         }
         let mut res = String::new();
         for line in text {
+            // Blank space added to fill the line isn't part of the original text.
             if matches!(a, Alignment::Minus) {
-                let mut line = line.0;
+                let mut line = line.unpadded().to_string();
                 line.push_str(&res);
                 res = line;
             } else {
-                res.push_str(&line.0);
+                res.push_str(line.unpadded());
+            }
+        }
+        res
+    }
+}
+impl TextMeasure for Split {
+    /// With a width of 0, every grapheme counts as a line, just like in trim().
+    fn measure(&self, text: &String, width: usize) -> usize {
+        // An empty string still takes up a line.
+        column_chunks(text, width).len().max(1)
+    }
+}
+#[doc(hidden)]
+/// Pads text with blank space, or cuts it off, so that it's exactly as long as the width inputted.
+pub(crate) fn pad(text: &str, width: usize) -> String {
+    let text = &text[..fit(text, width)];
+    format!("{}{}", text, " ".repeat(width - self::width(text)))
+}
+#[doc(hidden)]
+/// Pads text with blank space so that it's at least as long as the width inputted, keeping track of the blank space added.
+fn padded(mut text: String, width: usize) -> TrimmedText {
+    let pad = width.saturating_sub(self::width(&text));
+    text.push_str(&" ".repeat(pad));
+    TrimmedText { pad, ..TrimmedText::new(text) }
+}
+#[doc(hidden)]
+/// Breaks text up into lines of words, where each line fits in the width inputted (with a space between each word).
+/// Words that are too long to fit on a line on their own are broken up.
+/// With a width of 0, every grapheme of every word gets a line of its own.
+fn wrap_words(text: &str, width: usize) -> Vec<Vec<String>> {
+    let mut lines: Vec<Vec<String>> = Vec::new();
+    let mut line: Vec<String> = Vec::new();
+    let mut length = 0;
+    for word in text.split_whitespace() {
+        for piece in column_chunks(word, width) {
+            let piece = &word[piece];
+            let size = self::width(piece);
+            // Starts a new line if the piece won't fit on this one, including the space before it.
+            if !line.is_empty() && length + 1 + size > width {
+                lines.push(std::mem::take(&mut line));
+                length = 0;
+            }
+            if !line.is_empty() {
+                length += 1;
+            }
+            length += size;
+            line.push(piece.to_string());
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+#[doc(hidden)]
+/// Breaks text up into lines between words, keeping track of where each line is in the original text.
+/// Returns the byte range of each line, along with the range of the whitespace cut out after it.
+/// Words that are too long to fit on a line on their own are broken up.
+/// With a width of 0, every grapheme of every word gets a line of its own.
+fn wrap_ranges(text: &str, width: usize) -> Vec<(Range<usize>, Range<usize>)> {
+    let mut res = Vec::new();
+    // The start of the current line, the end of the words on it so far, and how long those words are.
+    let (mut start, mut end, mut length) = (0, 0, 0);
+    // Pairs each word with its position in the text.
+    let words = text.split_whitespace().map(|x| (x.as_ptr() as usize - text.as_ptr() as usize, x));
+    for (i, word) in words {
+        let gap = self::width(&text[end..i]);
+        let size = self::width(word);
+        if length + gap + size <= width {
+            end = i + word.len();
+            length += gap + size;
+            continue;
+        }
+        if length > 0 || (gap > 0 && size <= width) {
+            // The word doesn't fit, so it starts a new line. The whitespace before it is cut out.
+            // If it's indented too far to fit on the first line, the first line is left blank.
+            res.push((start..end, end..i));
+            start = i;
+            end = i;
+            length = 0;
+            if size <= width {
+                end = i + word.len();
+                length = size;
+                continue;
+            }
+        }
+        // The word can't fit on a line on its own, so it's broken up. The last piece is kept on the current line.
+        let base = start;
+        let mut chunks = column_chunks(&text[base..i + word.len()], width).into_iter().peekable();
+        while let Some(chunk) = chunks.next() {
+            let chunk = base + chunk.start..base + chunk.end;
+            if chunks.peek().is_none() {
+                end = chunk.end;
+                length = self::width(&text[chunk]);
+            } else {
+                res.push((chunk.clone(), chunk.end..chunk.end));
+                start = chunk.end;
+            }
+        }
+    }
+    // Trailing whitespace is kept if it fits, and cut out otherwise.
+    if length + self::width(&text[end..]) <= width {
+        end = text.len();
+    }
+    res.push((start..end, end..text.len()));
+    res
+}
+#[derive(Debug)]
+/// This strategy wraps text onto multiple lines between words. Words are only broken up if they don't fit on a line on their own.
+/// Whitespace inside of a line is kept as it is, and whitespace where a line was wrapped is cut out and kept,
+/// so the original text can be restored exactly. Short lines are padded with blank space.
+/// On a grid of 0 width, every grapheme of every word gets a line of its own, so the lines overflow the grid.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::{TextMeasure, TrimStrategy, TrimmedText, WordWrap};
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 10, 4).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let text = "This is a  little bit too big, extraordinarily.".to_string();
+/// assert_eq!(WordWrap.measure(&text, 10), 5);
+/// let v = WordWrap.trim(text.clone(), &process, grid::Alignment::Plus);
+/// let lines = v.iter().map(|x| x.text.as_str()).collect::<Vec<_>>();
+/// assert_eq!(lines, vec!["This is a ", "little bit", "too big,  ", "extraordin", "arily.    "]);
+/// assert_eq!(WordWrap.back(v, &process, grid::Alignment::Plus), text);
+/// let v = WordWrap.trim(text.clone(), &process, grid::Alignment::Minus);
+/// assert_eq!(WordWrap.back(v, &process, grid::Alignment::Minus), text);
+/// # Ok(())
+/// # }
+/// ```
+pub struct WordWrap;
+impl Display for WordWrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", WordWrap)
+    }
+}
+impl TrimStrategy for WordWrap {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let width = chunk.width();
+        let mut res = wrap_ranges(&text, width)
+            .into_iter()
+            .map(|(range, cut)| TrimmedText {
+                cut: Some(text[cut.clone()].to_string()).filter(|_| !cut.is_empty()),
+                range: Some(range.clone()),
+                ..padded(text[range].to_string(), width)
+            })
+            .collect::<Vec<_>>();
+        if matches!(a, Alignment::Minus) {
+            res.reverse();
+        }
+        res
+    }
+    fn back(&mut self, mut text: Vec<TrimmedText>, _: &DrawProcess, a: Alignment) -> Self::Input {
+        if matches!(a, Alignment::Minus) {
+            text.reverse();
+        }
+        let mut res = String::new();
+        for line in text {
+            res.push_str(line.unpadded());
+            if let Some(cut) = &line.cut {
+                res.push_str(cut);
+            }
+        }
+        res
+    }
+}
+impl TextMeasure for WordWrap {
+    /// With a width of 0, every grapheme of every word counts as a line, just like in trim().
+    fn measure(&self, text: &String, width: usize) -> usize {
+        wrap_ranges(text, width).len()
+    }
+}
+#[derive(Debug)]
+/// This strategy wraps text onto multiple lines between words, and then fully justifies it:
+/// space is spread out between the words so that every line but the last one reaches both edges of the grid.
+/// The last line is left-aligned, and padded with blank space.
+/// Words that don't fit on a line on their own are split up.
+/// Information loss: Whitespace between words is replaced by single spaces when the text is restored.
+/// On a grid of 0 width, every grapheme of every word gets a line of its own, so the lines overflow the grid.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::Justify;
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 12, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let v = Justify.trim("This is a little bit too big.".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::new("This   is  a".to_string()), TrimmedText::new("little   bit".to_string()), TrimmedText::new("too big.    ".to_string())], v);
+/// assert_eq!(Justify.back(v, &process, grid::Alignment::Plus), "This is a little bit too big.".to_string());
+/// # Ok(())
+/// # }
+/// ```
+pub struct Justify;
+impl Display for Justify {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", Justify)
+    }
+}
+impl TrimStrategy for Justify {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let width = chunk.width();
+        let lines = wrap_words(&text, width);
+        let last = lines.len() - 1;
+        let mut res = lines
+            .into_iter()
+            .enumerate()
+            .map(|(i, words)| {
+                if i == last || words.len() < 2 {
+                    return padded(words.join(" "), width);
+                }
+                // Spreads the leftover space out between the words, giving the leftmost gaps any extra.
+                let gaps = words.len() - 1;
+                let space = width - words.iter().map(|x| self::width(x)).sum::<usize>();
+                let mut line = String::new();
+                for (j, word) in words.iter().enumerate() {
+                    line.push_str(word);
+                    if j < gaps {
+                        let size = space / gaps + if j < space % gaps { 1 } else { 0 };
+                        line.push_str(&" ".repeat(size));
+                    }
+                }
+                TrimmedText::new(line)
+            })
+            .collect::<Vec<_>>();
+        if matches!(a, Alignment::Minus) {
+            // Reverses the direction if we're in the minus direction.
+            res.reverse();
+        }
+        res
+    }
+    fn back(&mut self, mut text: Vec<TrimmedText>, _: &DrawProcess, a: Alignment) -> Self::Input {
+        if matches!(a, Alignment::Minus) {
+            text.reverse();
+        }
+        text.iter().flat_map(|x| x.text.split_whitespace()).collect::<Vec<_>>().join(" ")
+    }
+}
+/// The input for the SpaceBetween strategy: two fragments of text, placed at opposite ends of a line.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
+pub struct Ends(pub String, pub String);
+impl Display for Ends {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.0, self.1)
+    }
+}
+#[derive(Debug)]
+/// This strategy places two fragments of text on a single line, one on the left edge and one on the right edge,
+/// with blank space in between. This is useful for headers, such as a title with a clock on the other side.
+/// If the fragments don't fit, the left fragment is cut off first (there's always at least one space between them).
+/// Information loss: Cut off text is lost, as are spaces at the start of the right fragment. The range of each line
+/// is the part of the left fragment it shows. Lines that don't have a range are split apart at the longest run of spaces,
+/// so fragments containing long runs of spaces might not be restored correctly.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::{Ends, SpaceBetween};
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 12, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let v = SpaceBetween.trim(Ends("Title".to_string(), "12:00".to_string()), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::new("Title  12:00".to_string())], v);
+/// assert_eq!(SpaceBetween.back(v, &process, grid::Alignment::Plus), Ends("Title".to_string(), "12:00".to_string()));
+/// let v = SpaceBetween.trim(Ends("Long title".to_string(), "12:00".to_string()), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::new("Long t 12:00".to_string())], v);
+/// let v = SpaceBetween.trim(Ends("Title".to_string(), String::new()), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::new("Title       ".to_string())], v);
+/// assert_eq!(SpaceBetween.back(v, &process, grid::Alignment::Plus), Ends("Title".to_string(), String::new()));
+/// # Ok(())
+/// # }
+/// ```
+pub struct SpaceBetween;
+impl Display for SpaceBetween {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", SpaceBetween)
+    }
+}
+impl TrimStrategy for SpaceBetween {
+    type Input = Ends;
+    fn trim(&mut self, text: Ends, chunk: &DrawProcess, _: Alignment) -> Vec<TrimmedText> {
+        let width = chunk.width();
+        // The right fragment keeps as much of its end as fits.
+        let mut used = 0;
+        let start = text
+            .1
+            .grapheme_indices(true)
+            .rev()
+            .take_while(|(_, x)| {
+                used += grapheme_width(x);
+                used <= width
+            })
+            .last()
+            .map_or(text.1.len(), |(i, _)| i);
+        let right = &text.1[start..];
+        // The left fragment gets whatever's left over, leaving room for a space if there's anything on the right.
+        let space = width - self::width(right);
+        let left = &text.0[..fit(&text.0, if right.is_empty() { space } else { space.saturating_sub(1) })];
+        let mut res = pad(left, space);
+        res.push_str(right);
+        vec![TrimmedText {
+            range: Some(0..left.len()),
+            ..TrimmedText::new(res)
+        }]
+    }
+    fn back(&mut self, text: Vec<TrimmedText>, _: &DrawProcess, _: Alignment) -> Self::Input {
+        let line = text.into_iter().next().expect("Safe unwrap");
+        if let Some(range) = line.range.clone().filter(|range| line.text.is_char_boundary(range.end)) {
+            // The left fragment is at the start of the line, and the right fragment (if there is one) is after the blank space.
+            let (left, right) = line.text.split_at(range.end);
+            return Ends(left.to_string(), right.trim_start_matches(' ').to_string());
+        }
+        let line = line.text;
+        let trimmed = line.trim();
+        // Finds the longest run of spaces, which is where the fragments were split apart.
+        let mut best = (0, 0);
+        let mut start = None;
+        for (i, c) in trimmed.char_indices() {
+            match (c == ' ', start) {
+                (true, None) => start = Some(i),
+                (false, Some(first)) => {
+                    if i - first > best.1 - best.0 {
+                        best = (first, i);
+                    }
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        Ends(trimmed[..best.0].to_string(), trimmed[best.1..].to_string())
+    }
+}
+/// How the Numeric strategy formats a number: how many decimals there's room for, and whether missing decimals are filled in with zeros.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NumericFormat {
+    /// The number of decimals there's room for.
+    pub decimals: usize,
+    /// Whether missing decimals are filled in with zeros.
+    pub zeros: bool,
+}
+/// The input for the Numeric strategy: a number, and the format it's shown in.
+/// Tuples can't be displayed, so this takes the place of (String, NumericFormat), which can be converted into it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
+pub struct Number(pub String, pub NumericFormat);
+impl Display for Number {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+impl From<(String, NumericFormat)> for Number {
+    fn from((text, format): (String, NumericFormat)) -> Self {
+        Number(text, format)
+    }
+}
+#[doc(hidden)]
+/// Checks whether text is a number: an optional sign, digits (which can be separated by commas), and optional decimals.
+fn is_number(text: &str) -> bool {
+    let text = text.strip_prefix(|c| c == '-' || c == '+').unwrap_or(text);
+    let (whole, decimals) = text.split_once('.').unwrap_or((text, ""));
+    let digits = |x: &str, separators: bool| x.chars().all(|c| c.is_ascii_digit() || (separators && c == ','));
+    !whole.starts_with(',') && digits(whole, true) && digits(decimals, false) && text.chars().any(|c| c.is_ascii_digit())
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// This strategy formats numbers for columns of a table. Numbers are right-aligned, with their decimal points lined up:
+/// there's always room for the number of decimals in the format, even if a number has fewer decimals (or none at all).
+/// Extra decimals are cut off (not rounded). If the format has zeros set, missing decimals are filled in with zeros.
+/// Numbers that don't fit are replaced with '#' characters, rather than showing a misleading number.
+/// Text that isn't a number (such as "N/A") is right-aligned as it is, without a decimal point to line up,
+/// and is also replaced with '#' characters if it doesn't fit.
+/// Information loss: Cut off decimals are lost, and zeros added are kept. The format is lost, so the default format is restored.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::{Number, Numeric, NumericFormat};
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 8, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let format = NumericFormat { decimals: 2, zeros: false };
+/// let number = |text: &str| Number::from((text.to_string(), format));
+/// assert_eq!(vec![TrimmedText::new("   12.5 ".to_string())], Numeric.trim(number("12.5"), &process, grid::Alignment::Plus));
+/// assert_eq!(vec![TrimmedText::new(" 1000.25".to_string())], Numeric.trim(number("1000.2575"), &process, grid::Alignment::Plus));
+/// assert_eq!(vec![TrimmedText::new("    7   ".to_string())], Numeric.trim(number("7"), &process, grid::Alignment::Plus));
+/// assert_eq!(vec![TrimmedText::new("########".to_string())], Numeric.trim(number("123456789"), &process, grid::Alignment::Plus));
+/// assert_eq!(vec![TrimmedText::new("     N/A".to_string())], Numeric.trim(number("N/A"), &process, grid::Alignment::Plus));
+/// let format = NumericFormat { decimals: 2, zeros: true };
+/// assert_eq!(vec![TrimmedText::new("    7.00".to_string())], Numeric.trim(Number("7".to_string(), format), &process, grid::Alignment::Plus));
+/// # Ok(())
+/// # }
+/// ```
+pub struct Numeric;
+impl Display for Numeric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl TrimStrategy for Numeric {
+    type Input = Number;
+    fn trim(&mut self, text: Number, chunk: &DrawProcess, _: Alignment) -> Vec<TrimmedText> {
+        let width = chunk.width();
+        let Number(text, format) = text;
+        let text = text.trim();
+        let mut res = text.to_string();
+        if is_number(text) && format.decimals != 0 {
+            let (whole, decimals) = match text.find('.') {
+                Some(i) => (&text[..i], Some(&text[i + 1..])),
+                None => (text, None),
+            };
+            res = whole.to_string();
+            let filler = if format.zeros { "0" } else { " " };
+            let decimals = decimals.filter(|x| !x.is_empty() || format.zeros);
+            // The decimal point is left out if there aren't any decimals, but the space for it is kept.
+            res.push_str(if decimals.is_some() || format.zeros { "." } else { " " });
+            let decimals = decimals.unwrap_or("").graphemes(true).chain(filler.graphemes(true).cycle());
+            res.extend(decimals.take(format.decimals));
+        } else if is_number(text) {
+            res = text.split('.').next().unwrap_or("").to_string();
+        }
+        let length = self::width(&res);
+        if length > width {
+            return vec![TrimmedText::new("#".repeat(width))];
+        }
+        vec![TrimmedText::new(format!("{}{}", " ".repeat(width - length), res))]
+    }
+    fn back(&mut self, text: Vec<TrimmedText>, _: &DrawProcess, _: Alignment) -> Self::Input {
+        Number(text.into_iter().next().expect("Safe unwrap").text.trim().to_string(), NumericFormat::default())
+    }
+}
+#[doc(hidden)]
+/// Finds the indentation at the start of a line: leading whitespace, quote markers ("> ") and a list marker ("- ", "1. ").
+/// Returns the indentation, and the indentation continuation lines should have instead.
+fn indentation(text: &str) -> (&str, String) {
+    let mut end = text.len() - text.trim_start().len();
+    let mut continuation = text[..end].to_string();
+    loop {
+        let rest = &text[end..];
+        if rest.starts_with("> ") {
+            // Quotes are repeated on every line.
+            continuation.push_str("> ");
+            end += 2;
+            continue;
+        }
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let marker = ["- ", "* ", "+ ", "• "]
+            .iter()
+            .find(|x| rest.starts_with(*x))
+            .map(|x| x.len())
+            .or_else(|| Some(digits + 2).filter(|_| digits != 0 && (rest[digits..].starts_with(". ") || rest[digits..].starts_with(") "))));
+        if let Some(len) = marker {
+            // List markers are replaced by blank space, so the text lines up after them.
+            continuation.push_str(&" ".repeat(width(&rest[..len])));
+            end += len;
+        }
+        break;
+    }
+    (&text[..end], continuation)
+}
+#[derive(Debug)]
+/// This strategy wraps text onto multiple lines between words, keeping the text's indentation.
+/// Leading whitespace, list markers (such as "- " or "1. ") and quote markers ("> ") are detected at the start of the text.
+/// Continuation lines are indented to line up with the text after the list marker, and quote markers are repeated.
+/// If the indentation takes up the entire width, it's ignored on continuation lines.
+/// It also adds blank space to any short lines to make sure every bit of blank space is refreshed.
+/// Information loss: Whitespace between words is replaced by single spaces when the text is restored.
+/// On a grid of 0 width, every grapheme of every word gets a line of its own, so the lines overflow the grid.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::Indented;
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 12, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let v = Indented.trim("- A list item that wraps".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::new("- A list    ".to_string()), TrimmedText::new("  item that ".to_string()), TrimmedText::new("  wraps     ".to_string())], v);
+/// assert_eq!(Indented.back(v, &process, grid::Alignment::Plus), "- A list item that wraps".to_string());
+/// let v = Indented.trim("> Quoted text".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::new("> Quoted    ".to_string()), TrimmedText::new("> text      ".to_string())], v);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Indented;
+impl Display for Indented {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", Indented)
+    }
+}
+impl TrimStrategy for Indented {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let width = chunk.width();
+        let (first, mut continuation) = indentation(&text);
+        let indent = self::width(first);
+        let (first, body, wrap_width) = if indent >= width {
+            continuation.clear();
+            ("", text.as_str(), width)
+        } else {
+            let (first, body) = text.split_at(first.len());
+            (first, body, width - indent)
+        };
+        let mut res = wrap_words(body, wrap_width)
+            .into_iter()
+            .enumerate()
+            .map(|(i, words)| {
+                let prefix = if i == 0 { first } else { continuation.as_str() };
+                padded(format!("{}{}", prefix, words.join(" ")), width)
+            })
+            .collect::<Vec<_>>();
+        if matches!(a, Alignment::Minus) {
+            // Reverses the direction if we're in the minus direction.
+            res.reverse();
+        }
+        res
+    }
+    fn back(&mut self, mut text: Vec<TrimmedText>, _: &DrawProcess, a: Alignment) -> Self::Input {
+        if matches!(a, Alignment::Minus) {
+            text.reverse();
+        }
+        let first = text.first().map(|x| x.text.clone()).unwrap_or_default();
+        let (indent, continuation) = indentation(&first);
+        let mut res = indent.to_string();
+        let words = text
+            .iter()
+            .enumerate()
+            .flat_map(|(i, line)| {
+                let line = if i == 0 { &line.text[indent.len()..] } else { line.text.strip_prefix(continuation.as_str()).unwrap_or(&line.text) };
+                line.split_whitespace()
+            })
+            .collect::<Vec<_>>();
+        res.push_str(&words.join(" "));
+        res
+    }
+}
+#[derive(Debug)]
+/// This strategy wraps another strategy, adding a gutter with line numbers to the left of each line.
+/// Numbers are right-aligned in a gutter of the width inputted, followed by a space. The text is trimmed by the inner
+/// strategy to the space left over. If a number is too big for the gutter, only its last digits are shown.
+/// By default, logical lines (each piece of text added) are numbered, and continuation lines have a blank gutter.
+/// Visual lines (every line produced) can be numbered instead.
+/// The next number is stored in the strategy, and goes up every time text is trimmed - even if the text doesn't fit.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::{LineNumbers, Split};
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 8, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let mut strategy = LineNumbers::new(Split, 2);
+/// assert_eq!(vec![TrimmedText::new(" 1 Short".to_string())], strategy.trim("Short".to_string(), &process, grid::Alignment::Plus));
+/// let v = strategy.trim("Long line".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::new(" 2 Long ".to_string()), TrimmedText::new("   line ".to_string())], v);
+/// assert_eq!(strategy.back(v, &process, grid::Alignment::Plus), "Long line".to_string());
+/// let mut strategy = LineNumbers::new(Split, 2).visual().starting_at(9);
+/// let v = strategy.trim("Long line".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::new(" 9 Long ".to_string()), TrimmedText::new("10 line ".to_string())], v);
+/// # Ok(())
+/// # }
+/// ```
+pub struct LineNumbers<T: TrimStrategy> {
+    pub inner: T,
+    width: usize,
+    visual: bool,
+    next: usize,
+}
+impl<T: TrimStrategy> LineNumbers<T> {
+    /// Wraps a strategy, with a gutter wide enough for numbers of the width inputted. Numbering starts at 1.
+    pub fn new(inner: T, width: usize) -> LineNumbers<T> {
+        LineNumbers {
+            inner,
+            width,
+            visual: false,
+            next: 1,
+        }
+    }
+    /// Numbers every line produced, instead of every piece of text added.
+    pub fn visual(mut self) -> Self {
+        self.visual = true;
+        self
+    }
+    /// Sets the next number.
+    pub fn starting_at(mut self, next: usize) -> Self {
+        self.next = next;
+        self
+    }
+    /// Gets the next number.
+    pub fn next_number(&self) -> usize {
+        self.next
+    }
+}
+impl<T: TrimStrategy> Display for LineNumbers<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<T: TrimStrategy> TrimStrategy for LineNumbers<T> {
+    type Input = T::Input;
+    fn trim(&mut self, text: T::Input, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let inner = chunk.narrowed(self.width + 1);
+        let mut res = self.inner.trim(text, &inner, a);
+        if matches!(a, Alignment::Minus) {
+            // Numbers go from top to bottom, so the lines are put back in order first.
+            res.reverse();
+        }
+        for (i, line) in res.iter_mut().enumerate() {
+            let number = if i == 0 || self.visual {
+                let number = self.next.to_string();
+                self.next += 1;
+                number[number.len().saturating_sub(self.width)..].to_string()
+            } else {
+                String::new()
+            };
+            let number = format!("{:>width$} ", number, width = self.width);
+            line.decorate(&number, "", inner.width(), chunk.width());
+        }
+        if matches!(a, Alignment::Minus) {
+            res.reverse();
+        }
+        res
+    }
+    fn back(&mut self, text: Vec<TrimmedText>, chunk: &DrawProcess, a: Alignment) -> Self::Input {
+        let inner = chunk.narrowed(self.width + 1);
+        let text = text
+            .into_iter()
+            .map(|mut x| {
+                x.undecorate(self.width + 1, inner.width());
+                x
+            })
+            .collect();
+        self.inner.back(text, &inner, a)
+    }
+}
+#[derive(Debug)]
+/// This strategy wraps another strategy, adding a constant prefix and suffix to every line (such as a tag, or "| " borders).
+/// The text is trimmed by the inner strategy to the space left over, and each line is then padded or cut off to that space,
+/// so that the suffixes line up. The prefix and suffix are counted in the lead and pad of each line (see TrimmedText).
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::{Decorate, Split};
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let mut strategy = Decorate::new(Split, "| ", " |");
+/// let v = strategy.trim("Some stuff".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::new("| Some s |".to_string()), TrimmedText::new("| tuff   |".to_string())], v);
+/// // The prefix and suffix are counted as part of the lead and pad, so the text trimmed can still be found.
+/// assert_eq!((v[1].lead, v[1].unpadded(), v[1].pad), (2, "tuff", 4));
+/// assert_eq!(strategy.back(v, &process, grid::Alignment::Plus), "Some stuff".to_string());
+/// # Ok(())
+/// # }
+/// ```
+pub struct Decorate<T: TrimStrategy> {
+    pub inner: T,
+    prefix: String,
+    suffix: String,
+}
+impl<T: TrimStrategy> Decorate<T> {
+    /// Wraps a strategy, adding the prefix and suffix inputted to each line.
+    pub fn new(inner: T, prefix: &str, suffix: &str) -> Decorate<T> {
+        Decorate {
+            inner,
+            prefix: prefix.to_string(),
+            suffix: suffix.to_string(),
+        }
+    }
+    #[doc(hidden)]
+    /// Gets the width taken up by the prefix and suffix.
+    fn decoration_width(&self) -> (usize, usize) {
+        (width(&self.prefix), width(&self.suffix))
+    }
+}
+impl<T: TrimStrategy> Display for Decorate<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<T: TrimStrategy> TrimStrategy for Decorate<T> {
+    type Input = T::Input;
+    fn trim(&mut self, text: T::Input, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let (prefix, suffix) = self.decoration_width();
+        let inner = chunk.narrowed(prefix + suffix);
+        let mut res = self.inner.trim(text, &inner, a);
+        for line in res.iter_mut() {
+            line.decorate(&self.prefix, &self.suffix, inner.width(), chunk.width());
+        }
+        res
+    }
+    fn back(&mut self, text: Vec<TrimmedText>, chunk: &DrawProcess, a: Alignment) -> Self::Input {
+        let (prefix, suffix) = self.decoration_width();
+        let inner = chunk.narrowed(prefix + suffix);
+        let text = text
+            .into_iter()
+            .map(|mut x| {
+                x.undecorate(prefix, inner.width());
+                x
+            })
+            .collect();
+        self.inner.back(text, &inner, a)
+    }
+}
+#[derive(Debug)]
+/// This strategy wraps another strategy, marking where it wraps text, so that wrapped lines can be told apart from text
+/// that was added on separate lines. Continuation lines start with one marker (such as "↪"), and lines that continue
+/// onto the next line end with another (such as "\\"). Either marker can be left empty.
+/// Room for both markers is kept on every line, so that the text lines up, and the text is trimmed by the inner
+/// strategy to the space left over. Restoring text removes the markers.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::{Split, TrimStrategy, TrimmedText, WrapMarkers};
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 8, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let mut strategy = WrapMarkers::new(Split, "↪", "\\");
+/// let v = strategy.trim("A long line".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::new(" A long\\".to_string()), TrimmedText::new("↪ line  ".to_string())], v);
+/// assert_eq!(strategy.back(v, &process, grid::Alignment::Plus), "A long line".to_string());
+/// let v = strategy.trim("Short".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::new(" Short  ".to_string())], v);
+/// # Ok(())
+/// # }
+/// ```
+pub struct WrapMarkers<T: TrimStrategy> {
+    pub inner: T,
+    start: String,
+    end: String,
+}
+impl<T: TrimStrategy> WrapMarkers<T> {
+    /// Wraps a strategy, starting continuation lines with the first marker inputted, and ending lines that continue with the second.
+    pub fn new(inner: T, start: &str, end: &str) -> WrapMarkers<T> {
+        WrapMarkers {
+            inner,
+            start: start.to_string(),
+            end: end.to_string(),
+        }
+    }
+    #[doc(hidden)]
+    /// Gets the width taken up by the markers.
+    fn marker_width(&self) -> (usize, usize) {
+        (width(&self.start), width(&self.end))
+    }
+}
+impl<T: TrimStrategy> Display for WrapMarkers<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<T: TrimStrategy> TrimStrategy for WrapMarkers<T> {
+    type Input = T::Input;
+    fn trim(&mut self, text: T::Input, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let (start, end) = self.marker_width();
+        let inner = chunk.narrowed(start + end);
+        let mut res = self.inner.trim(text, &inner, a);
+        if matches!(a, Alignment::Minus) {
+            // Text wraps from top to bottom, so the lines are put back in order first.
+            res.reverse();
+        }
+        let last = res.len().saturating_sub(1);
+        for (i, line) in res.iter_mut().enumerate() {
+            let prefix = if i > 0 { self.start.clone() } else { " ".repeat(start) };
+            let suffix = if i < last { self.end.clone() } else { " ".repeat(end) };
+            line.decorate(&prefix, &suffix, inner.width(), chunk.width());
+        }
+        if matches!(a, Alignment::Minus) {
+            res.reverse();
+        }
+        res
+    }
+    fn back(&mut self, text: Vec<TrimmedText>, chunk: &DrawProcess, a: Alignment) -> Self::Input {
+        let (start, end) = self.marker_width();
+        let inner = chunk.narrowed(start + end);
+        let text = text
+            .into_iter()
+            .map(|mut x| {
+                x.undecorate(start, inner.width());
+                x
+            })
+            .collect();
+        self.inner.back(text, &inner, a)
+    }
+}
+impl<T: TextMeasure> TextMeasure for WrapMarkers<T> {
+    fn measure(&self, text: &Self::Input, width: usize) -> usize {
+        let (start, end) = self.marker_width();
+        self.inner.measure(text, width.saturating_sub(start + end))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+/// This strategy lines up tab-separated columns (elastic tabstops). Each tab is replaced with enough blank space
+/// to reach the end of its column, plus a gap. Lines are then cut off or padded to fit, like with Truncate.
+/// Column widths are shared by every line trimmed with the same strategy, so lines in different processes
+/// (such as the rows of two side-by-side panels) line up. Call fit() with every line first, so the columns are wide enough.
+/// Restoring text turns the tabs into blank space - the tabs themselves are lost.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::{ElasticTabs, TrimStrategy};
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 15, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let lines = vec!["Name:\tgrid_ui".to_string(), "Version:\t0.1.1".to_string()];
+/// let mut strategy = ElasticTabs::new(1);
+/// strategy.fit_lines(&lines);
+/// assert_eq!(strategy.widths(), &[8]);
+/// let v = strategy.trim(lines[0].clone(), &process, grid::Alignment::Plus);
+/// assert_eq!(v[0].text, "Name:    grid_u".to_string());
+/// let v = strategy.trim(lines[1].clone(), &process, grid::Alignment::Plus);
+/// assert_eq!(v[0].text, "Version: 0.1.1 ".to_string());
+/// assert_eq!(strategy.back(v, &process, grid::Alignment::Plus), "Version: 0.1.1".to_string());
+/// # Ok(())
+/// # }
+/// ```
+pub struct ElasticTabs {
+    widths: Vec<usize>,
+    gap: usize,
+}
+impl ElasticTabs {
+    /// Creates a strategy without any columns yet, which leaves gap columns of blank space after each column.
+    pub fn new(gap: usize) -> ElasticTabs {
+        ElasticTabs { widths: Vec::new(), gap }
+    }
+    /// Widens the columns so that every tab-separated cell in the text fits. The last cell in a line isn't a column, so it's ignored.
+    pub fn fit(&mut self, text: &str) {
+        let cells = text.split('\t').collect::<Vec<_>>();
+        for (i, cell) in cells.iter().take(cells.len() - 1).enumerate() {
+            if i == self.widths.len() {
+                self.widths.push(0);
+            }
+            self.widths[i] = self.widths[i].max(width(cell));
+        }
+    }
+    /// Widens the columns so that every line inputted fits.
+    pub fn fit_lines<S: AsRef<str>>(&mut self, lines: &[S]) {
+        for line in lines {
+            self.fit(line.as_ref());
+        }
+    }
+    /// Gets the width of each column, not counting the gap.
+    pub fn widths(&self) -> &[usize] {
+        &self.widths
+    }
+    /// Forgets every column, so the strategy can be fitted to different lines.
+    pub fn reset(&mut self) {
+        self.widths.clear();
+    }
+    #[doc(hidden)]
+    /// Replaces each tab with the blank space needed to reach the end of its column.
+    fn expand(&self, text: &str) -> String {
+        let cells = text.split('\t').collect::<Vec<_>>();
+        let mut res = String::new();
+        for (i, cell) in cells.iter().enumerate() {
+            res.push_str(cell);
+            if i + 1 < cells.len() {
+                let column = self.widths.get(i).copied().unwrap_or(0);
+                res.push_str(&" ".repeat(column.saturating_sub(width(cell)) + self.gap));
+            }
+        }
+        res
+    }
+}
+impl Display for ElasticTabs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl TrimStrategy for ElasticTabs {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        Truncate.trim(self.expand(&text), chunk, a)
+    }
+    fn back(&mut self, text: Vec<TrimmedText>, chunk: &DrawProcess, a: Alignment) -> Self::Input {
+        Truncate.back(text, chunk, a)
+    }
+}
+impl TextMeasure for ElasticTabs {
+    fn measure(&self, _: &String, _: usize) -> usize {
+        1
+    }
+}
+#[derive(Debug)]
+/// This strategy wraps another strategy, centering each line it produces within the grid (such as for titles and headers).
+/// Blank space added by the inner strategy is moved to both sides of the line, with any extra space on the right.
+/// Restoring text removes the blank space added, and then restores the text using the inner strategy.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::{Center, Split, TrimStrategy};
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let mut strategy = Center(Split);
+/// let v = strategy.trim("A title, centered".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(v[0].text, "A title, c".to_string());
+/// assert_eq!(v[1].text, " entered  ".to_string());
+/// assert_eq!(strategy.back(v, &process, grid::Alignment::Plus), "A title, centered".to_string());
+/// # Ok(())
+/// # }
+/// ```
+pub struct Center<T: TrimStrategy>(pub T);
+impl<T: TrimStrategy> Display for Center<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<T: TrimStrategy> TrimStrategy for Center<T> {
+    type Input = T::Input;
+    fn trim(&mut self, text: T::Input, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let mut res = self.0.trim(text, chunk, a);
+        for line in res.iter_mut() {
+            let content = line.unpadded().to_string();
+            let space = chunk.width().saturating_sub(width(&content));
+            let (lead, pad) = (space / 2, space - space / 2);
+            line.move_styles(line.lead, lead);
+            line.set_text(format!("{}{}{}", " ".repeat(lead), content, " ".repeat(pad)));
+            line.lead = lead;
+            line.pad = pad;
+        }
+        res
+    }
+    fn back(&mut self, text: Vec<TrimmedText>, chunk: &DrawProcess, a: Alignment) -> Self::Input {
+        self.0.back(text, chunk, a)
+    }
+}
+impl<T: TextMeasure> TextMeasure for Center<T> {
+    fn measure(&self, text: &Self::Input, width: usize) -> usize {
+        self.0.measure(text, width)
+    }
+}
+#[cfg(feature = "unicode-normalization")]
+/**
+Converts text to Unicode Normalization Form C, where characters are composed whenever possible.
+Text that looks the same can be made up of different sequences of characters (such as "é" and "e\u{301}"),
+so normalizing it first means it's measured, compared and hashed the same way.
+# Example
+``` rust
+# use grid_ui::trim::*;
+# fn main() {
+assert_eq!(normalize("e\u{301}"), "é".to_string());
+assert_eq!(normalize("é"), "é".to_string());
+# }
+```
+*/
+pub fn normalize(text: &str) -> String {
+    text.nfc().collect()
+}
+#[cfg(feature = "unicode-normalization")]
+#[derive(Debug)]
+/// This strategy wraps another strategy, normalizing text (see normalize()) before the inner strategy trims it.
+/// This way, text that looks the same is trimmed into the same lines, so it's redrawn (or not) consistently by diffing
+/// handlers.
+/// Restoring text returns the normalized text, so the original sequence of characters is lost.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::{Normalized, Split, TrimStrategy};
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 5, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let mut strategy = Normalized(Split);
+/// let composed = strategy.trim("café".to_string(), &process, grid::Alignment::Plus);
+/// let decomposed = strategy.trim("cafe\u{301}".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(composed, decomposed);
+/// assert_eq!(strategy.back(decomposed, &process, grid::Alignment::Plus), "café".to_string());
+/// # Ok(())
+/// # }
+/// ```
+pub struct Normalized<T: TrimStrategy<Input = String>>(pub T);
+#[cfg(feature = "unicode-normalization")]
+impl<T: TrimStrategy<Input = String>> Display for Normalized<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+#[cfg(feature = "unicode-normalization")]
+impl<T: TrimStrategy<Input = String>> TrimStrategy for Normalized<T> {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        self.0.trim(normalize(&text), chunk, a)
+    }
+    fn back(&mut self, text: Vec<TrimmedText>, chunk: &DrawProcess, a: Alignment) -> Self::Input {
+        self.0.back(text, chunk, a)
+    }
+}
+#[cfg(feature = "unicode-normalization")]
+impl<T: TextMeasure<Input = String>> TextMeasure for Normalized<T> {
+    fn measure(&self, text: &String, width: usize) -> usize {
+        self.0.measure(&normalize(text), width)
+    }
+}
+#[derive(Debug)]
+/// This strategy wraps another strategy, so that styled text can be trimmed by it. The text is trimmed without its styles,
+/// so only the text itself counts towards its width, and the styles are then put back onto each line, using the range
+/// of the original text that each line displays. Blank space added by the inner strategy is printed in the default style.
+/// Lines that don't know which range they display (see TrimmedText::range) are printed without styles.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::style::{Style, StyledText};
+/// # use grid_ui::trim::{Split, Styled, TrimStrategy};
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 6, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let red = Style::rgb(255, 0, 0);
+/// let text = StyledText::new().plain("a ").span("red", red).plain(" word");
+/// let mut strategy = Styled(Split);
+/// let v = strategy.trim(text.clone(), &process, grid::Alignment::Plus);
+/// assert_eq!(v[0].text, "a red ".to_string());
+/// assert_eq!(v[0].styles, vec![(2, red), (5, Style::new())]);
+/// assert_eq!(v[1].text, "word  ".to_string());
+/// assert!(v[1].styles.is_empty());
+/// assert_eq!(strategy.back(v, &process, grid::Alignment::Plus), text);
+/// process.add_to_section(text, &mut strategy, grid::Alignment::Plus);
+/// let mut output = String::new();
+/// process.print(&mut grid_ui::out::OutToString, &mut output)?;
+/// assert_eq!(output, "a red \nword  \n      \n".to_string());
+/// # Ok(())
+/// # }
+/// ```
+pub struct Styled<T: TrimStrategy<Input = String>>(pub T);
+impl<T: TrimStrategy<Input = String>> Display for Styled<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<T: TrimStrategy<Input = String>> TrimStrategy for Styled<T> {
+    type Input = StyledText;
+    fn trim(&mut self, text: StyledText, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let ranges = text.ranges();
+        let mut res = self.0.trim(text.text(), chunk, a);
+        for line in res.iter_mut() {
+            let shown = match line.range.clone() {
+                Some(shown) => shown,
+                None => continue,
+            };
+            let mut styles: Vec<(usize, Style)> = Vec::new();
+            let visible = ranges
+                .iter()
+                .filter(|(range, style)| *style != Style::new() && range.start < shown.end && shown.start < range.end);
+            for (range, style) in visible {
+                let start = range.start.max(shown.start) - shown.start + line.lead;
+                let end = range.end.min(shown.end) - shown.start + line.lead;
+                for (offset, style) in [(start, *style), (end, Style::new())] {
+                    // A style that starts where the last one ends replaces the switch back to the default style.
+                    match styles.last_mut() {
+                        Some(last) if last.0 == offset => last.1 = style,
+                        _ => styles.push((offset, style)),
+                    }
+                }
             }
+            line.styles = styles;
         }
         res
     }
+    fn back(&mut self, text: Vec<TrimmedText>, chunk: &DrawProcess, a: Alignment) -> Self::Input {
+        let mut ranges: Vec<(Range<usize>, Style)> = Vec::new();
+        for line in &text {
+            let shown = match line.range.clone() {
+                Some(shown) => shown,
+                None => continue,
+            };
+            let original = |offset: usize| (shown.start + offset.saturating_sub(line.lead)).min(shown.end);
+            for (i, (offset, style)) in line.styles.iter().enumerate() {
+                let end = line.styles.get(i + 1).map_or(line.text.len(), |next| next.0);
+                let range = original(*offset)..original(end);
+                if *style != Style::new() && !range.is_empty() {
+                    ranges.push((range, *style));
+                }
+            }
+        }
+        ranges.sort_by_key(|(range, _)| range.start);
+        // Spans split up between lines are joined back together.
+        let mut joined: Vec<(Range<usize>, Style)> = Vec::new();
+        for (range, style) in ranges {
+            match joined.last_mut() {
+                Some(last) if last.0.end == range.start && last.1 == style => last.0.end = range.end,
+                _ => joined.push((range, style)),
+            }
+        }
+        StyledText::from_ranges(&self.0.back(text, chunk, a), &joined)
+    }
+}
+impl<T: TextMeasure<Input = String>> TextMeasure for Styled<T> {
+    fn measure(&self, text: &StyledText, width: usize) -> usize {
+        self.0.measure(&text.text(), width)
+    }
 }